@@ -0,0 +1,180 @@
+//! Step-by-step playback of simulations in the terminal.
+//!
+//! A simulation implements [`Animation`]; [`Player`] redraws its frames with
+//! ANSI escape codes and reads single keys from the terminal:
+//!
+//! - `space` play / pause
+//! - `n` step one frame (while paused)
+//! - `+` / `-` speed up / slow down
+//! - `q` quit
+//!
+//! Keys are read by switching the terminal to non-canonical mode with `stty`,
+//! so controls are only available when stdin is a terminal; otherwise the
+//! animation simply plays through.
+
+use std::{
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::cli;
+
+pub trait Animation {
+    fn title(&self) -> String;
+
+    /// Renders the current state.
+    fn frame(&self) -> String;
+
+    /// Moves the simulation one step forward, `false` once it is over.
+    fn advance(&mut self) -> bool;
+}
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+const MIN_FPS: f64 = 0.5;
+const MAX_FPS: f64 = 1000.0;
+
+pub struct Player {
+    fps: f64,
+    paused: bool,
+}
+
+struct RawTerminal {
+    saved: String,
+}
+
+impl RawTerminal {
+    fn stty(args: &[&str]) -> Option<String> {
+        let output = Command::new("stty")
+            .args(args)
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    }
+
+    /// Reads return immediately, with or without a key.
+    fn enable() -> Option<RawTerminal> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+        let saved = Self::stty(&["-g"])?;
+        Self::stty(&["-icanon", "-echo", "min", "0", "time", "0"])?;
+        Some(RawTerminal { saved })
+    }
+
+    fn key(&self) -> Option<u8> {
+        let mut buf = [0_u8; 1];
+        match io::stdin().read(&mut buf) {
+            Ok(1) => Some(buf[0]),
+            _ => None,
+        }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        Self::stty(&[&self.saved]);
+    }
+}
+
+impl Player {
+    pub fn new() -> Self {
+        Player {
+            fps: 10.0,
+            paused: false,
+        }
+    }
+
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = fps.clamp(MIN_FPS, MAX_FPS);
+        self
+    }
+
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Player configured from `--fps N` and `--paused`.
+    pub fn from_cli() -> Self {
+        let fps = cli::value("--fps").and_then(|fps| fps.parse().ok()).unwrap_or(10.0);
+        Player::new().fps(fps).paused(cli::flag("--paused"))
+    }
+
+    fn draw<A: Animation>(&self, out: &mut impl Write, animation: &A, frame_idx: usize, ended: bool) -> io::Result<()> {
+        let state = match (ended, self.paused) {
+            (true, _) => "ended",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        write!(out, "{}{}\n\n{}\n", CLEAR, animation.title(), animation.frame())?;
+        writeln!(
+            out,
+            "frame {} | {:.1} fps | {} | [space] play/pause [n] step [+/-] speed [q] quit",
+            frame_idx, self.fps, state
+        )?;
+        out.flush()
+    }
+
+    pub fn play<A: Animation>(mut self, animation: &mut A) -> io::Result<()> {
+        let terminal = RawTerminal::enable();
+        if terminal.is_none() {
+            self.paused = false;
+        }
+        let mut out = io::stdout().lock();
+        write!(out, "{}", HIDE_CURSOR)?;
+
+        let mut frame_idx = 0;
+        let mut ended = false;
+        self.draw(&mut out, animation, frame_idx, ended)?;
+
+        let mut last_frame = Instant::now();
+        loop {
+            let mut step = false;
+            if let Some(key) = terminal.as_ref().and_then(|t| t.key()) {
+                match key {
+                    b'q' => break,
+                    b' ' => self.paused = !self.paused,
+                    b'n' => step = self.paused,
+                    b'+' => self.fps = (self.fps * 2.0).min(MAX_FPS),
+                    b'-' => self.fps = (self.fps / 2.0).max(MIN_FPS),
+                    _ => continue,
+                }
+                self.draw(&mut out, animation, frame_idx, ended)?;
+            }
+
+            let due = last_frame.elapsed() >= Duration::from_secs_f64(1.0 / self.fps);
+            if !ended && (step || (!self.paused && due)) {
+                ended = !animation.advance();
+                if !ended {
+                    frame_idx += 1;
+                }
+                last_frame = Instant::now();
+                self.draw(&mut out, animation, frame_idx, ended)?;
+            }
+
+            // Without a terminal there is nobody to press `q`.
+            if ended && terminal.is_none() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        write!(out, "{}", SHOW_CURSOR)?;
+        out.flush()
+    }
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,255 @@
+//! Benchmark history and regression detection.
+//!
+//! Days wrap their parts in [`time`], so `--time` reports how long each part
+//! took without counting the examples or the process start.
+//!
+//! Every `aoc bench` run appends one line per day and part to a local
+//! history file, tagged with the git commit it was measured on (read from
+//! `.git/HEAD`, no `git` binary needed) and the date. Lines hold the
+//! tab-separated commit, date, year, day, part and time in nanoseconds; lines
+//! written before the year was recorded are read as [`DEFAULT_YEAR`].
+//!
+//! [`compare`] matches a run with the best time ever recorded for the same
+//! year, day and part.
+
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{cli, years::DEFAULT_YEAR};
+
+/// Runs a part, printing how long it took on stderr when `--time` is given.
+pub fn time<T>(part: u32, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let res = f();
+    if cli::flag("--time") {
+        eprintln!("Time:: Part {} ====>     {}", part, start.elapsed().as_nanos());
+    }
+    res
+}
+
+/// Times printed by [`time`], indexed by part.
+pub fn part_times(stderr: &str) -> Vec<Duration> {
+    stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("Time:: Part "))
+        .filter_map(|line| line.split_once("====>"))
+        .filter_map(|(_, nanos)| nanos.trim().parse::<u64>().ok())
+        .map(Duration::from_nanos)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub commit: String,
+    pub date: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub time: Duration,
+}
+
+impl Display for Sample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.commit,
+            self.date,
+            self.year,
+            self.day,
+            self.part,
+            self.time.as_nanos()
+        )
+    }
+}
+
+impl std::str::FromStr for Sample {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let number = |field: &str| field.parse::<u64>().map_err(|e| format!("Invalid history line ({}): {}", s, e));
+        let (commit, date, year, day, part, nanos) = match fields[..] {
+            [commit, date, year, day, part, nanos] => (commit, date, number(year)? as u32, day, part, nanos),
+            [commit, date, day, part, nanos] => (commit, date, DEFAULT_YEAR, day, part, nanos),
+            _ => return Err(format!("Invalid history line: {}", s)),
+        };
+        Ok(Sample {
+            commit: commit.to_owned(),
+            date: date.to_owned(),
+            year,
+            day: number(day)? as u32,
+            part: number(part)? as u32,
+            time: Duration::from_nanos(number(nanos)?),
+        })
+    }
+}
+
+pub fn load_history<P: AsRef<Path>>(path: P) -> io::Result<Vec<Sample>> {
+    match fs::read_to_string(path) {
+        Ok(data) => data
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.parse().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append_history<P: AsRef<Path>>(path: P, samples: &[Sample]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    samples.iter().try_for_each(|sample| writeln!(file, "{}", sample))
+}
+
+/// Commit checked out in the repository at `root`, `unknown` outside of git.
+pub fn git_commit<P: AsRef<Path>>(root: P) -> String {
+    let git = root.as_ref().join(".git");
+    let Ok(head) = fs::read_to_string(git.join("HEAD")) else {
+        return "unknown".to_owned();
+    };
+    let Some(reference) = head.trim().strip_prefix("ref: ") else {
+        // Detached HEAD holds the hash itself.
+        return head.trim().to_owned();
+    };
+
+    if let Ok(hash) = fs::read_to_string(git.join(reference)) {
+        return hash.trim().to_owned();
+    }
+    // Refs are moved to `packed-refs` by `git gc`.
+    fs::read_to_string(git.join("packed-refs"))
+        .ok()
+        .and_then(|packed| {
+            packed
+                .lines()
+                .filter_map(|line| line.split_once(' '))
+                .find(|(_, name)| *name == reference)
+                .map(|(hash, _)| hash.to_owned())
+        })
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Current UTC time as `YYYY-MM-DDTHH:MM:SSZ`.
+pub fn now() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3_600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub time: Duration,
+    /// Best earlier time, with the commit it was measured on.
+    pub best: Option<(Duration, String)>,
+}
+
+impl Comparison {
+    /// Slowdown against the best time, `0.25` being 25% slower.
+    pub fn change(&self) -> Option<f64> {
+        self.best
+            .as_ref()
+            .map(|(best, _)| self.time.as_secs_f64() / best.as_secs_f64().max(1e-9) - 1.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// Compares the samples of a run with the best times of `history`.
+pub fn compare(run: &[Sample], history: &[Sample]) -> Vec<Comparison> {
+    run.iter()
+        .map(|sample| Comparison {
+            year: sample.year,
+            day: sample.day,
+            part: sample.part,
+            time: sample.time,
+            best: history
+                .iter()
+                .filter(|old| old.year == sample.year && old.day == sample.day && old.part == sample.part)
+                .min_by_key(|old| old.time)
+                .map(|old| (old.time, old.commit.clone())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(commit: &str, year: u32, day: u32, part: u32, millis: u64) -> Sample {
+        Sample {
+            commit: commit.to_owned(),
+            date: "2022-12-01T00:00:00Z".to_owned(),
+            year,
+            day,
+            part,
+            time: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn parses_samples() {
+        let sample = sample("abc123", 2022, 5, 2, 12);
+        assert_eq!(sample.to_string().parse::<Sample>(), Ok(sample.clone()));
+        // Lines written before the year column read as the default year.
+        assert_eq!("abc123\t2022-12-01T00:00:00Z\t5\t2\t12000000".parse::<Sample>(), Ok(sample));
+        assert!("abc123\t2022-12-01T00:00:00Z\t5\t2".parse::<Sample>().is_err());
+        assert!("abc123\t2022-12-01T00:00:00Z\t5\t2\tfast".parse::<Sample>().is_err());
+    }
+
+    #[test]
+    fn compares_with_the_best_time() {
+        let history = [
+            sample("old", 2022, 1, 1, 100),
+            sample("best", 2022, 1, 1, 80),
+            sample("other-part", 2022, 1, 2, 10),
+            sample("other-year", 2021, 1, 1, 1),
+        ];
+        let run = [sample("new", 2022, 1, 1, 92), sample("new", 2022, 2, 1, 5)];
+        let comparisons = compare(&run, &history);
+
+        assert_eq!(comparisons[0].best, Some((Duration::from_millis(80), "best".to_owned())));
+        let change = comparisons[0].change().unwrap();
+        assert!((change - 0.15).abs() < 1e-9, "{}", change);
+        assert!(comparisons[0].is_regression(0.10));
+        assert!(!comparisons[0].is_regression(0.20));
+
+        assert_eq!(comparisons[1].best, None);
+        assert!(!comparisons[1].is_regression(0.0));
+    }
+
+    #[test]
+    fn reads_part_times() {
+        let stderr = "Warning:: something\nTime:: Part 1 ====>     1500\nTime:: Part 2 ====>     42\n";
+        assert_eq!(part_times(stderr), vec![Duration::from_nanos(1500), Duration::from_nanos(42)]);
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    fmt::{self, Display},
+    io::{self, BufRead, Result},
+};
+
+use aoc::{
+    bench,
+    cli,
+    input,
+    render::{Align, Table},
+    years,
+};
+
+const DAY: &'static str = "01";
+
+#[derive(Debug)]
+enum CaloriesError {
+    Io(io::Error),
+    /// 1-based line number and its content.
+    Parse(usize, String),
+    /// Line whose calories no longer fit the elf total.
+    Overflow(usize),
+}
+
+impl Display for CaloriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaloriesError::Io(e) => write!(f, "Read error: {}", e),
+            CaloriesError::Parse(line, text) => write!(f, "Line {}: invalid calories {:?}", line, text),
+            CaloriesError::Overflow(line) => write!(f, "Line {}: elf total overflows u64", line),
+        }
+    }
+}
+
+impl From<CaloriesError> for io::Error {
+    fn from(e: CaloriesError) -> Self {
+        match e {
+            CaloriesError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+/// One elf of the inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Elf {
+    /// 1-based position in the input.
+    index: usize,
+    items: usize,
+    total: u64,
+}
+
+impl Ord for Elf {
+    /// By total, the first elf in the input ranking higher on ties.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total
+            .cmp(&other.total)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` largest elves seen so far.
+struct TopK {
+    k: usize,
+    // Min-heap, the smallest kept elf on top.
+    best: BinaryHeap<Reverse<Elf>>,
+    elves: usize,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            best: BinaryHeap::with_capacity(k + 1),
+            elves: 0,
+        }
+    }
+
+    fn push(&mut self, elf: Elf) {
+        self.elves += 1;
+        self.best.push(Reverse(elf));
+        if self.best.len() > self.k {
+            self.best.pop();
+        }
+    }
+
+    /// Kept elves, largest first.
+    fn best(&self) -> Vec<Elf> {
+        let mut best: Vec<Elf> = self.best.iter().map(|Reverse(elf)| *elf).collect();
+        best.sort_by(|a, b| b.cmp(a));
+        best
+    }
+
+    /// Kept totals, largest first.
+    fn totals(&self) -> Vec<u64> {
+        self.best().iter().map(|elf| elf.total).collect()
+    }
+
+    /// Sum of the `n` largest totals, `None` on overflow.
+    fn sum(&self, n: usize) -> Option<u64> {
+        self.totals().iter().take(n).try_fold(0u64, |sum, total| sum.checked_add(*total))
+    }
+}
+
+/// Reads the inventory line by line, handing each elf to `f` once complete.
+fn for_each_elf<R: BufRead>(mut reader: R, mut f: impl FnMut(Elf)) -> std::result::Result<(), CaloriesError> {
+    let mut line = String::new();
+    let mut elf: Option<Elf> = None;
+    let mut line_number = 0;
+    let mut index = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(CaloriesError::Io)? == 0 {
+            break;
+        }
+        line_number += 1;
+
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            if let Some(elf) = elf.take() {
+                f(elf);
+            }
+            continue;
+        }
+        let calories = text
+            .parse::<u64>()
+            .map_err(|_| CaloriesError::Parse(line_number, text.to_owned()))?;
+        let current = elf.get_or_insert_with(|| {
+            index += 1;
+            Elf { index, items: 0, total: 0 }
+        });
+        current.items += 1;
+        current.total = current
+            .total
+            .checked_add(calories)
+            .ok_or(CaloriesError::Overflow(line_number))?;
+    }
+    if let Some(elf) = elf {
+        f(elf);
+    }
+    Ok(())
+}
+
+/// Keeps the `k` largest elves only.
+fn stream_calories<R: BufRead>(reader: R, k: usize) -> std::result::Result<TopK, CaloriesError> {
+    let mut top = TopK::new(k);
+    for_each_elf(reader, |elf| top.push(elf))?;
+    Ok(top)
+}
+
+struct Stats {
+    elves: usize,
+    items: usize,
+    min: u64,
+    max: u64,
+    mean: f64,
+    median: f64,
+    /// Nearest-rank percentiles of the totals.
+    percentiles: Vec<(u32, u64)>,
+    /// Lowest and highest total of each bucket, with its elf count.
+    histogram: Vec<(u64, u64, usize)>,
+}
+
+impl Stats {
+    const PERCENTILES: [u32; 5] = [10, 25, 75, 90, 99];
+    const BUCKETS: u64 = 10;
+
+    fn new(elves: &[Elf]) -> Option<Self> {
+        let mut totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        totals.sort();
+        let (min, max) = (*totals.first()?, *totals.last()?);
+        let len = totals.len();
+
+        let median = if len % 2 == 1 {
+            totals[len / 2] as f64
+        } else {
+            (totals[len / 2 - 1] as f64 + totals[len / 2] as f64) / 2.0
+        };
+        let percentiles = Self::PERCENTILES
+            .iter()
+            .map(|p| (*p, totals[((*p as usize * len).div_ceil(100)).max(1) - 1]))
+            .collect();
+
+        let width = ((max - min) / Self::BUCKETS + 1).max(1);
+        let mut histogram: Vec<(u64, u64, usize)> = (0..=(max - min) / width)
+            .map(|bucket| min + bucket * width)
+            .map(|start| (start, start.saturating_add(width - 1), 0))
+            .collect();
+        totals.iter().for_each(|total| histogram[((total - min) / width) as usize].2 += 1);
+
+        Some(Stats {
+            elves: len,
+            items: elves.iter().map(|elf| elf.items).sum(),
+            min,
+            max,
+            mean: totals.iter().map(|total| *total as f64).sum::<f64>() / len as f64,
+            median,
+            percentiles,
+            histogram,
+        })
+    }
+}
+
+/// The `k` best elves, with the elves tied with the k-th one left out of the
+/// top.
+struct ElfReport {
+    k: usize,
+    top: Vec<Elf>,
+    tied: Vec<Elf>,
+    stats: Option<Stats>,
+}
+
+fn elf_report<R: BufRead>(reader: R, k: usize) -> std::result::Result<ElfReport, CaloriesError> {
+    let mut elves = vec![];
+    for_each_elf(reader, |elf| elves.push(elf))?;
+    let stats = Stats::new(&elves);
+
+    elves.sort_by(|a, b| b.cmp(a));
+    let rest = elves.split_off(k.min(elves.len()));
+    let tied = match elves.last() {
+        Some(last) if k > 0 => rest.into_iter().take_while(|elf| elf.total == last.total).collect(),
+        _ => vec![],
+    };
+    Ok(ElfReport { k, top: elves, tied, stats })
+}
+
+impl Display for ElfReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(stats) = &self.stats else {
+            return writeln!(f, "No elf in the inventory");
+        };
+
+        let mut table = Table::new(&format!("Top {} of {} elves", self.k, stats.elves))
+            .header(vec!["Rank".to_owned(), "Elf".to_owned(), "Items".to_owned(), "Calories".to_owned()])
+            .align(Align::Right);
+        self.top.iter().enumerate().for_each(|(rank, elf)| {
+            table.push_row(vec![
+                (rank + 1).to_string(),
+                elf.index.to_string(),
+                elf.items.to_string(),
+                elf.total.to_string(),
+            ])
+        });
+        writeln!(f, "{}", table)?;
+        if !self.tied.is_empty() {
+            let indices: Vec<String> = self.tied.iter().map(|elf| elf.index.to_string()).collect();
+            writeln!(
+                f,
+                "Tie at rank {}: elves {} also carry {} calories",
+                self.k,
+                indices.join(", "),
+                self.tied[0].total
+            )?;
+        }
+
+        writeln!(f, "Items   : {} ({:.1} per elf)", stats.items, stats.items as f64 / stats.elves as f64)?;
+        writeln!(f, "Calories: min {}, max {}, mean {:.1}, median {:.1}", stats.min, stats.max, stats.mean, stats.median)?;
+        let percentiles: Vec<String> = stats.percentiles.iter().map(|(p, total)| format!("p{} {}", p, total)).collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        let largest = stats.histogram.iter().map(|(_, _, count)| *count).max().unwrap_or(1);
+        stats.histogram.iter().try_for_each(|(low, high, count)| {
+            writeln!(f, "{:>8} - {:>8} | {:<40} {}", low, high, "█".repeat(count * 40 / largest), count)
+        })
+    }
+}
+
+fn part1(data: &str) -> u64 {
+    stream_calories(data.as_bytes(), 1)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .sum(1)
+        .unwrap()
+}
+
+fn part2(data: &str) -> u64 {
+    stream_calories(data.as_bytes(), 3)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .sum(3)
+        .expect("Top three total overflows u64")
+}
+
+/// Both parts in a single pass over `--input`, without loading it.
+fn stream(reader: Box<dyn BufRead>) -> Result<()> {
+    let top = stream_calories(reader, 3)?;
+    let overflow = || io::Error::new(io::ErrorKind::InvalidData, "Top three total overflows u64");
+    println!("Input:: Part 1 ====>     {}", top.sum(1).ok_or_else(overflow)?);
+    println!("Input:: Part 2 ====>     {}", top.sum(3).ok_or_else(overflow)?);
+    println!("Input:: Elves  ====>     {}", top.elves);
+    println!("--------------------------------------------------------");
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    println!();
+    println!("---( AOC{} - Day {} )-----------------------[Rust]----", years::DEFAULT_YEAR, DAY);
+
+    if cli::flag("--stream") {
+        return stream(cli::input_reader()?.unwrap_or_else(|| Box::new(input!("day01.in").as_bytes())));
+    }
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day01.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day01.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(k) = cli::value("--top") {
+        let k = k
+            .parse::<usize>()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid --top: {}", k)))?;
+        print!("{}", elf_report(input_data.as_bytes(), k)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn format_elves(elves: &[Vec<u64>]) -> String {
+        elves
+            .iter()
+            .map(|calories| {
+                calories
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    #[test]
+    fn parse_format_round_trip() {
+        prop::check(
+            "each blank-line block sums to one elf",
+            |rng| rng.vec(6, |rng| rng.vec(5, |rng| rng.below(100_000))),
+            |elves| {
+                if elves.is_empty() || elves.iter().any(|calories| calories.is_empty()) {
+                    return true;
+                }
+                let mut totals = elves.iter().map(|c| c.iter().sum()).collect::<Vec<u64>>();
+                totals.sort_by(|a, b| b.cmp(a));
+                stream_calories(format_elves(elves).as_bytes(), elves.len())
+                    .unwrap()
+                    .totals()
+                    == totals
+            },
+        );
+    }
+
+    #[test]
+    fn top_three_bounds() {
+        prop::check(
+            "max <= top three <= 3 * max",
+            |rng| rng.vec(6, |rng| rng.vec(5, |rng| rng.below(100_000))),
+            |elves| {
+                if elves.is_empty() || elves.iter().any(|calories| calories.is_empty()) {
+                    return true;
+                }
+                let data = format_elves(elves);
+                part1(&data) <= part2(&data) && part2(&data) <= 3 * part1(&data)
+            },
+        );
+    }
+
+    #[test]
+    fn streaming_matches_sorting() {
+        prop::check(
+            "streamed top k is the head of the sorted totals",
+            |rng| {
+                (
+                    rng.vec(8, |rng| rng.vec(5, |rng| rng.below(100_000))),
+                    1 + rng.below(5) as usize,
+                )
+            },
+            |(elves, k)| {
+                if elves.is_empty() || elves.iter().any(|calories| calories.is_empty()) {
+                    return true;
+                }
+                let mut totals = elves.iter().map(|c| c.iter().sum()).collect::<Vec<u64>>();
+                totals.sort_by(|a, b| b.cmp(a));
+                totals.truncate(*k);
+                let top = stream_calories(format_elves(elves).as_bytes(), *k).unwrap();
+                top.totals() == totals && top.elves == elves.len()
+            },
+        );
+    }
+
+    #[test]
+    fn report_keeps_indices_and_ties() {
+        let report = elf_report("5\n\n1\n2\n\n4\n\n3\n\n3\n".as_bytes(), 2).unwrap();
+        let top: Vec<(usize, usize, u64)> = report
+            .top
+            .iter()
+            .map(|elf| (elf.index, elf.items, elf.total))
+            .collect();
+        assert_eq!(top, vec![(1, 1, 5), (3, 1, 4)]);
+        assert!(report.tied.is_empty());
+
+        let report = elf_report("5\n\n1\n2\n\n4\n\n3\n\n3\n".as_bytes(), 3).unwrap();
+        assert_eq!(report.top.last().map(|elf| elf.index), Some(2));
+        let tied: Vec<usize> = report.tied.iter().map(|elf| elf.index).collect();
+        assert_eq!(tied, vec![4, 5]);
+
+        let stats = report.stats.unwrap();
+        assert_eq!((stats.elves, stats.items, stats.min, stats.max), (5, 6, 3, 5));
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 3.6);
+        assert_eq!(stats.percentiles[0], (10, 3));
+        assert_eq!(stats.percentiles[4], (99, 5));
+        assert_eq!(stats.histogram, vec![(3, 3, 3), (4, 4, 1), (5, 5, 1)]);
+    }
+
+    #[test]
+    fn stats_up_to_the_largest_total() {
+        let data = format!("0\n\n{}\n", u64::MAX);
+        let stats = elf_report(data.as_bytes(), 1).unwrap().stats.unwrap();
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(stats.histogram.first().map(|bucket| (bucket.0, bucket.2)), Some((0, 1)));
+        assert_eq!(stats.histogram.last().map(|bucket| (bucket.1, bucket.2)), Some((u64::MAX, 1)));
+    }
+
+    #[test]
+    fn streaming_errors() {
+        let overflow = format!("1\n\n{}\n1\n", u64::MAX);
+        assert!(matches!(
+            stream_calories(overflow.as_bytes(), 3),
+            Err(CaloriesError::Overflow(4))
+        ));
+        assert!(matches!(
+            stream_calories("1\n2\n\nabc\n".as_bytes(), 3),
+            Err(CaloriesError::Parse(4, text)) if text == "abc"
+        ));
+    }
+}
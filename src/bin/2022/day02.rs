@@ -0,0 +1,699 @@
+use std::{
+    fs,
+    io::{self, Result},
+};
+
+use aoc::{
+    bench,
+    cli,
+    input,
+    render::{Align, Color, Table},
+    years,
+};
+
+const DAY: &'static str = "02";
+
+/// A shape, by its position in the [`Rules`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Shape(usize);
+
+#[derive(Debug)]
+struct ShapeRule {
+    name: String,
+    score: u64,
+    /// Code of the shape in the opponent column.
+    abc: char,
+    /// Code of the shape in the second column, read as a shape.
+    xyz: char,
+    /// Shapes this one wins against.
+    beats: Vec<usize>,
+}
+
+/// Rule table of a Rock-Paper-Scissors like game.
+#[derive(Debug)]
+struct Rules {
+    shapes: Vec<ShapeRule>,
+    /// Codes of the second column read as an outcome: lose, draw, win.
+    result_codes: [char; 3],
+}
+
+impl Rules {
+    /// Checks every two distinct shapes have exactly one winner.
+    fn new(shapes: Vec<ShapeRule>, result_codes: [char; 3]) -> std::result::Result<Rules, String> {
+        for (i, shape) in shapes.iter().enumerate() {
+            if let Some(j) = shape.beats.iter().find(|j| **j >= shapes.len()) {
+                return Err(format!("{} beats unknown shape #{}", shape.name, j));
+            }
+            for (j, other) in shapes.iter().enumerate() {
+                let (wins, loses) = (shape.beats.contains(&j), other.beats.contains(&i));
+                if i == j && wins {
+                    return Err(format!("{} beats itself", shape.name));
+                }
+                if i < j && wins == loses {
+                    return Err(format!("No single winner between {} and {}", shape.name, other.name));
+                }
+            }
+        }
+        Ok(Rules { shapes, result_codes })
+    }
+
+    /// Odd-sized cyclic game, listed so that each shape beats the ones an
+    /// odd number of places before it (wrapping). Shapes score 1, 2, ... and
+    /// are coded A, B, ... and ..., Y, Z.
+    fn cyclic(names: &[&str]) -> std::result::Result<Rules, String> {
+        let n = names.len();
+        if n.is_multiple_of(2) || !(3..=25).contains(&n) {
+            return Err(format!("A cyclic game needs an odd number of 3 to 25 shapes, not {}", n));
+        }
+        let shapes = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| ShapeRule {
+                name: name.to_string(),
+                score: i as u64 + 1,
+                abc: (b'A' + i as u8) as char,
+                xyz: (b'Z' + 1 + i as u8 - n as u8) as char,
+                beats: (0..n).filter(|j| (i + n - j) % n % 2 == 1).collect(),
+            })
+            .collect();
+        Rules::new(shapes, ['X', 'Y', 'Z'])
+    }
+
+    fn rps() -> Rules {
+        Rules::cyclic(&["Rock", "Paper", "Scissors"]).expect("Rock-Paper-Scissors rules")
+    }
+
+    fn rpsls() -> Rules {
+        Rules::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).expect("Rock-Paper-Scissors-Lizard-Spock rules")
+    }
+
+    /// `rps`, `rpsls` or the number of shapes of a cyclic game.
+    fn by_name(name: &str) -> std::result::Result<Rules, String> {
+        match name {
+            "rps" => Ok(Rules::rps()),
+            "rpsls" => Ok(Rules::rpsls()),
+            n => {
+                let n = n
+                    .parse::<usize>()
+                    .map_err(|_| format!("Unknown rules: {} (rps, rpsls or a number of shapes)", n))?;
+                let names: Vec<String> = (1..=n).map(|i| format!("Shape{}", i)).collect();
+                Rules::cyclic(&names.iter().map(|name| name.as_str()).collect::<Vec<&str>>())
+            }
+        }
+    }
+
+    fn rule(&self, shape: Shape) -> &ShapeRule {
+        &self.shapes[shape.0]
+    }
+
+    fn abc_shape(&self, code: char) -> Shape {
+        Shape(self.shapes.iter().position(|shape| shape.abc == code).expect("Shape"))
+    }
+
+    fn xyz_shape(&self, code: char) -> Shape {
+        Shape(self.shapes.iter().position(|shape| shape.xyz == code).expect("Shape"))
+    }
+
+    fn result_from_code(&self, code: char) -> RoundResult {
+        match self.result_codes.iter().position(|c| *c == code) {
+            Some(0) => Lose,
+            Some(1) => Draw,
+            Some(_) => Win,
+            None => panic!("Unknown code!"),
+        }
+    }
+
+    fn result(&self, oponent: Shape, user: Shape) -> RoundResult {
+        if user == oponent {
+            Draw
+        } else if self.rule(user).beats.contains(&oponent.0) {
+            Win
+        } else {
+            Lose
+        }
+    }
+
+    /// Shape getting `res` against `oponent`, the best scoring one when
+    /// several do.
+    fn shape_for(&self, oponent: Shape, res: &RoundResult) -> Shape {
+        (0..self.shapes.len())
+            .map(Shape)
+            .filter(|user| self.result(oponent, *user) == *res)
+            .max_by_key(|user| (self.rule(*user).score, std::cmp::Reverse(user.0)))
+            .expect("Every shape wins and loses against another one")
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum RoundResult {
+    Win,
+    Draw,
+    Lose,
+}
+
+use RoundResult::*;
+
+impl RoundResult {
+    fn name(&self) -> &'static str {
+        match *self {
+            Win => "Win",
+            Draw => "Draw",
+            Lose => "Lose",
+        }
+    }
+
+    fn score(&self) -> u64 {
+        match *self {
+            Win => 6,
+            Draw => 3,
+            Lose => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Round {
+    user: Shape,
+    res: RoundResult,
+}
+
+impl Round {
+    fn codes(line: &str) -> (char, char) {
+        let error_msg = format!("Line ({}) invalid!", line);
+
+        let opponent_code: char = line.chars().nth(0).expect(&error_msg);
+        let second_code: char = line.chars().nth(2).expect(&error_msg);
+        (opponent_code, second_code)
+    }
+
+    fn from_line_1(rules: &Rules, line: &str) -> Round {
+        let (opponent_code, user_code) = Round::codes(line);
+
+        let opponent = rules.abc_shape(opponent_code);
+        let user = rules.xyz_shape(user_code);
+        let res = rules.result(opponent, user);
+
+        Round { user, res }
+    }
+
+    fn from_line_2(rules: &Rules, line: &str) -> Round {
+        let (opponent_code, result_code) = Round::codes(line);
+
+        let opponent = rules.abc_shape(opponent_code);
+        let res = rules.result_from_code(result_code);
+        let user = rules.shape_for(opponent, &res);
+
+        Round { user, res }
+    }
+
+    fn score(&self, rules: &Rules) -> u64 {
+        self.res.score() + rules.rule(self.user).score
+    }
+}
+
+fn part1(rules: &Rules, data: &str) -> u64 {
+    data.lines()
+        .map(|line| Round::from_line_1(rules, line))
+        .map(|round| round.score(rules))
+        .sum()
+}
+
+fn part2(rules: &Rules, data: &str) -> u64 {
+    data.lines()
+        .map(|line| Round::from_line_2(rules, line))
+        .map(|round| round.score(rules))
+        .sum()
+}
+
+/// One line of the guide read both ways.
+struct Comparison {
+    opponent: Shape,
+    /// Second column as a shape, as in part 1.
+    shape: Round,
+    /// Second column as an outcome, as in part 2.
+    outcome: Round,
+}
+
+impl Comparison {
+    fn agree(&self) -> bool {
+        self.shape.user == self.outcome.user
+    }
+}
+
+fn compare(rules: &Rules, data: &str) -> Vec<Comparison> {
+    data.lines()
+        .map(|line| Comparison {
+            opponent: rules.abc_shape(Round::codes(line).0),
+            shape: Round::from_line_1(rules, line),
+            outcome: Round::from_line_2(rules, line),
+        })
+        .collect()
+}
+
+const COMPARISON_HEADER: [&str; 10] = [
+    "Round", "Opponent", "Shape", "Result", "Score", "Shape", "Result", "Score", "Difference", "Agree",
+];
+
+/// Cells of each round; the difference is the running part 2 total minus
+/// the part 1 one.
+fn comparison_rows(rules: &Rules, comparisons: &[Comparison]) -> Vec<Vec<String>> {
+    let mut difference: i64 = 0;
+    comparisons
+        .iter()
+        .enumerate()
+        .map(|(idx, comparison)| {
+            let (shape, outcome) = (comparison.shape.score(rules), comparison.outcome.score(rules));
+            difference += outcome as i64 - shape as i64;
+            vec![
+                (idx + 1).to_string(),
+                rules.rule(comparison.opponent).name.clone(),
+                rules.rule(comparison.shape.user).name.clone(),
+                comparison.shape.res.name().to_owned(),
+                shape.to_string(),
+                rules.rule(comparison.outcome.user).name.clone(),
+                comparison.outcome.res.name().to_owned(),
+                outcome.to_string(),
+                format!("{:+}", difference),
+                if comparison.agree() { "yes" } else { "" }.to_owned(),
+            ]
+        })
+        .collect()
+}
+
+fn comparison_table(rules: &Rules, comparisons: &[Comparison]) -> Table {
+    let agreeing = comparisons.iter().filter(|comparison| comparison.agree()).count();
+    let title = format!("Part 1 vs part 2, {} of {} rounds agree", agreeing, comparisons.len());
+    let mut table = Table::new(&title)
+        .header(COMPARISON_HEADER.iter().map(|column| column.to_string()).collect())
+        .align(Align::Right);
+    for (row, cells) in comparison_rows(rules, comparisons).into_iter().enumerate() {
+        table.push_row(cells);
+        if comparisons[row].agree() {
+            table = table.highlight(row, COMPARISON_HEADER.len() - 1, Color::Green);
+        }
+    }
+    table
+}
+
+fn comparison_csv(rules: &Rules, comparisons: &[Comparison]) -> String {
+    let header = [
+        "round",
+        "opponent",
+        "part1_shape",
+        "part1_result",
+        "part1_score",
+        "part2_shape",
+        "part2_result",
+        "part2_score",
+        "difference",
+        "agree",
+    ];
+    let mut csv = header.join(",") + "\n";
+    comparison_rows(rules, comparisons).into_iter().zip(comparisons).for_each(|(mut cells, comparison)| {
+        cells[9] = comparison.agree().to_string();
+        csv += &(cells.join(",") + "\n");
+    });
+    csv
+}
+
+/// What a [`Constraint`] counts in the rounds played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Counted {
+    Result(RoundResult),
+    Shape(Shape),
+}
+
+/// At most (or exactly) `max` rounds of `counted`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Constraint {
+    counted: Counted,
+    max: usize,
+    exact: bool,
+}
+
+impl Constraint {
+    /// Comma separated `NAME<=N` or `NAME=N`, `NAME` being `win`, `draw`,
+    /// `lose` or a shape name, e.g. `win<=100,rock=0`.
+    fn parse(rules: &Rules, spec: &str) -> std::result::Result<Vec<Constraint>, String> {
+        spec.split(',')
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (name, max, exact) = match part.split_once("<=") {
+                    Some((name, max)) => (name, max, false),
+                    None => part
+                        .split_once('=')
+                        .map(|(name, max)| (name, max, true))
+                        .ok_or_else(|| format!("Invalid constraint: {} (NAME<=N or NAME=N)", part))?,
+                };
+                let counted = match name.trim().to_lowercase().as_str() {
+                    "win" => Counted::Result(Win),
+                    "draw" => Counted::Result(Draw),
+                    "lose" => Counted::Result(Lose),
+                    name => rules
+                        .shapes
+                        .iter()
+                        .position(|shape| shape.name.to_lowercase() == name)
+                        .map(|idx| Counted::Shape(Shape(idx)))
+                        .ok_or_else(|| format!("Unknown result or shape: {}", name))?,
+                };
+                let max = max.trim().parse::<usize>().map_err(|_| format!("Invalid count: {}", max))?;
+                Ok(Constraint { counted, max, exact })
+            })
+            .collect()
+    }
+
+    fn counts(&self, round: &Round) -> bool {
+        match self.counted {
+            Counted::Result(res) => round.res == res,
+            Counted::Shape(shape) => round.user == shape,
+        }
+    }
+}
+
+/// Shapes to play, one per round, and their total score.
+#[derive(Debug)]
+struct Plan {
+    shapes: Vec<Shape>,
+    score: u64,
+}
+
+/// Largest table of choices, one per round and constraint counts, the solver
+/// fills.
+const MAX_CELLS: usize = 1 << 28;
+
+/// Best scoring shapes against `opponents` under `constraints`, `None` when
+/// they can't be met.
+///
+/// Dynamic programming over the rounds, the state being how much of each
+/// constraint is used so far: `O(rounds * shapes * Π(max + 1))`.
+fn optimize(rules: &Rules, opponents: &[Shape], constraints: &[Constraint]) -> std::result::Result<Option<Plan>, String> {
+    if constraints.iter().any(|constraint| constraint.exact && constraint.max > opponents.len()) {
+        return Ok(None);
+    }
+    // A limit above the number of rounds is always met.
+    let constraints: Vec<Constraint> = constraints
+        .iter()
+        .filter(|constraint| constraint.exact || constraint.max < opponents.len())
+        .copied()
+        .collect();
+
+    let mut strides = vec![];
+    let states = constraints.iter().try_fold(1usize, |states, constraint| {
+        strides.push(states);
+        states.checked_mul(constraint.max + 1)
+    });
+    let states = states
+        .filter(|states| states.saturating_mul(opponents.len()) <= MAX_CELLS)
+        .ok_or_else(|| format!("Constraints too loose, over {} choices to explore", MAX_CELLS))?;
+    let digit = |state: usize, k: usize| state / strides[k] % (constraints[k].max + 1);
+
+    let mut best: Vec<Option<u64>> = vec![None; states];
+    best[0] = Some(0);
+    // Shape played in each round to reach each state at its best.
+    let mut choices: Vec<Vec<u8>> = Vec::with_capacity(opponents.len());
+
+    for opponent in opponents {
+        // Each shape with its score and the constraints it uses.
+        let moves: Vec<(u8, u64, Vec<usize>)> = (0..rules.shapes.len())
+            .map(|user| {
+                let round = Round { user: Shape(user), res: rules.result(*opponent, Shape(user)) };
+                let counted = (0..constraints.len()).filter(|k| constraints[*k].counts(&round)).collect();
+                (user as u8, round.score(rules), counted)
+            })
+            .collect();
+        let mut next: Vec<Option<u64>> = vec![None; states];
+        let mut choice = vec![0u8; states];
+
+        for (state, score) in best.iter().enumerate() {
+            let Some(score) = score else { continue };
+            for (user, round_score, counted) in &moves {
+                if counted.iter().any(|k| digit(state, *k) == constraints[*k].max) {
+                    continue;
+                }
+                let to = state + counted.iter().map(|k| strides[*k]).sum::<usize>();
+                if next[to].is_none_or(|best| score + round_score > best) {
+                    next[to] = Some(score + round_score);
+                    choice[to] = *user;
+                }
+            }
+        }
+        best = next;
+        choices.push(choice);
+    }
+
+    let end = (0..states)
+        .filter(|state| {
+            (0..constraints.len()).all(|k| !constraints[k].exact || digit(*state, k) == constraints[k].max)
+        })
+        .filter_map(|state| best[state].map(|score| (score, state)))
+        .max();
+    let Some((score, mut state)) = end else {
+        return Ok(None);
+    };
+
+    let mut shapes = vec![];
+    for (opponent, choice) in opponents.iter().zip(&choices).rev() {
+        let user = Shape(choice[state] as usize);
+        let round = Round { user, res: rules.result(*opponent, user) };
+        state -= (0..constraints.len())
+            .filter(|k| constraints[*k].counts(&round))
+            .map(|k| strides[k])
+            .sum::<usize>();
+        shapes.push(user);
+    }
+    shapes.reverse();
+    Ok(Some(Plan { shapes, score }))
+}
+
+fn print_plan(rules: &Rules, data: &str, spec: &str) -> std::result::Result<(), String> {
+    let constraints = Constraint::parse(rules, spec)?;
+    let opponents: Vec<Shape> = data.lines().map(|line| rules.abc_shape(Round::codes(line).0)).collect();
+    let Some(plan) = optimize(rules, &opponents, &constraints)? else {
+        println!("Optim:: no way to play {} rounds under {}", opponents.len(), spec);
+        return Ok(());
+    };
+
+    let mut results = [0; 3];
+    let mut shapes = vec![0; rules.shapes.len()];
+    opponents.iter().zip(&plan.shapes).for_each(|(opponent, user)| {
+        results[[Lose, Draw, Win].iter().position(|res| *res == rules.result(*opponent, *user)).unwrap()] += 1;
+        shapes[user.0] += 1;
+    });
+    let shapes: Vec<String> = rules.shapes.iter().zip(shapes).map(|(shape, count)| format!("{} {}", shape.name, count)).collect();
+
+    println!("Optim:: Best score ====>  {}", plan.score);
+    println!("Optim:: Results ====>     {} wins, {} draws, {} losses", results[2], results[1], results[0]);
+    println!("Optim:: Shapes ====>      {}", shapes.join(", "));
+    println!("Optim:: Guide ====>       {} (part 1), {} (part 2)", part1(rules, data), part2(rules, data));
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    println!();
+    println!("---( AOC{} - Day {} )-----------------------[Rust]----", years::DEFAULT_YEAR, DAY);
+
+    let rules = match cli::value("--rules") {
+        Some(name) => Rules::by_name(&name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+        None => Rules::rps(),
+    };
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day02.test");
+        println!("Test :: Part 1 ====>     {:?}", part1(&rules, test_data));
+        println!("Test :: Part 2 ====>     {:?}", part2(&rules, test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day02.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {:?}", bench::time(1, || part1(&rules, input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {:?}", bench::time(2, || part2(&rules, input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if cli::flag("--compare") {
+        println!("{}", comparison_table(&rules, &compare(&rules, input_data)));
+    }
+    if let Some(path) = cli::value("--csv") {
+        fs::write(path, comparison_csv(&rules, &compare(&rules, input_data)))?;
+    }
+    if cli::flag("--optimize") {
+        let spec = cli::value("--optimize").filter(|spec| !spec.starts_with("--")).unwrap_or_default();
+        print_plan(&rules, input_data, &spec).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn all_rules() -> [Rules; 3] {
+        [Rules::rps(), Rules::rpsls(), Rules::by_name("7").unwrap()]
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        let all_rules = all_rules();
+        prop::check(
+            "ABC and XYZ codes give back the shape",
+            |rng| (rng.below(3) as usize, rng.below(7) as usize),
+            |(rules, idx)| {
+                let rules = &all_rules[*rules % 3];
+                let shape = Shape(*idx % rules.shapes.len());
+                rules.abc_shape(rules.rule(shape).abc) == shape && rules.xyz_shape(rules.rule(shape).xyz) == shape
+            },
+        );
+    }
+
+    #[test]
+    fn strategy_agrees_with_result() {
+        let all_rules = all_rules();
+        prop::check(
+            "playing the shape picked for a result gives that result",
+            |rng| (rng.below(3) as usize, rng.below(7) as usize, rng.below(3) as usize),
+            |(rules, opponent, code)| {
+                let rules = &all_rules[*rules % 3];
+                let opponent = rules.shapes[*opponent % rules.shapes.len()].abc;
+                let round = Round::from_line_2(rules, &format!("{} {}", opponent, rules.result_codes[*code % 3]));
+                let replayed = Round::from_line_1(rules, &format!("{} {}", opponent, rules.rule(round.user).xyz));
+                replayed.res == round.res && replayed.score(rules) == round.score(rules)
+            },
+        );
+    }
+
+    #[test]
+    fn results_are_antisymmetric() {
+        let all_rules = all_rules();
+        prop::check(
+            "a wins against b iff b loses against a",
+            |rng| (rng.below(3) as usize, rng.below(7) as usize, rng.below(7) as usize),
+            |(rules, a, b)| {
+                let rules = &all_rules[*rules % 3];
+                let (a, b) = (Shape(*a % rules.shapes.len()), Shape(*b % rules.shapes.len()));
+                let flipped = match rules.result(a, b) {
+                    Win => Lose,
+                    Draw => Draw,
+                    Lose => Win,
+                };
+                rules.result(b, a) == flipped
+            },
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rpsls();
+        let wins = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        let shape = |name: &str| Shape(rules.shapes.iter().position(|shape| shape.name == name).unwrap());
+        wins.iter()
+            .for_each(|(winner, loser)| assert_eq!(rules.result(shape(loser), shape(winner)), Win, "{} beats {}", winner, loser));
+
+        // Lizard (Z) against Rock (A): lost, 5 + 0. Asked to win against
+        // Rock, Spock (4) is played over Paper (2).
+        assert_eq!(part1(&rules, "A Z\n"), 5);
+        assert_eq!(part2(&rules, "A Z\n"), 4 + 6);
+        assert_eq!(part1(&rules, "E Z\nC X\n"), 5 + 3 + 3 + 3);
+    }
+
+    #[test]
+    fn comparison_csv_of_example() {
+        let rules = Rules::rps();
+        let comparisons = compare(&rules, input!("day02.test"));
+        assert_eq!(
+            comparison_csv(&rules, &comparisons),
+            "round,opponent,part1_shape,part1_result,part1_score,part2_shape,part2_result,part2_score,difference,agree\n\
+             1,Rock,Paper,Win,8,Rock,Draw,4,-4,false\n\
+             2,Paper,Rock,Lose,1,Rock,Lose,1,-4,true\n\
+             3,Scissors,Scissors,Draw,6,Rock,Win,7,-3,false\n"
+        );
+    }
+
+    #[test]
+    fn optimize_example() {
+        let rules = Rules::rps();
+        let opponents: Vec<Shape> = "ABC".chars().map(|code| rules.abc_shape(code)).collect();
+        let best = |spec: &str| optimize(&rules, &opponents, &Constraint::parse(&rules, spec).unwrap()).unwrap();
+
+        assert_eq!(best("").unwrap().score, 8 + 9 + 7);
+        assert_eq!(best("win<=1").unwrap().score, 8 + 5 + 6);
+        assert_eq!(best("win=0, draw=0").unwrap().score, 3 + 1 + 2);
+        assert_eq!(best("rock<=0,paper<=0").unwrap().score, 3 * 3 + 6 + 3);
+        assert!(best("win=2,draw=2").is_none());
+        assert!(Constraint::parse(&rules, "tie<=2").is_err());
+    }
+
+    #[test]
+    fn optimize_matches_brute_force() {
+        let rules = Rules::rpsls();
+        let names = ["win", "draw", "lose", "rock", "spock"];
+        prop::check(
+            "dynamic programming finds the best of all the plays",
+            |rng| (rng.vec(5, |rng| rng.below(5) as usize), rng.vec(2, |rng| (rng.below(5) as usize, rng.below(4) as usize, rng.bool()))),
+            |(opponents, constraints)| {
+                let opponents: Vec<Shape> = opponents.iter().map(|idx| Shape(idx % 5)).collect();
+                let spec: Vec<String> = constraints
+                    .iter()
+                    .map(|(name, max, exact)| format!("{}{}{}", names[name % 5], if *exact { "=" } else { "<=" }, max))
+                    .collect();
+                let constraints = Constraint::parse(&rules, &spec.join(",")).unwrap();
+
+                let mut brute: Option<u64> = None;
+                for play in 0..5usize.pow(opponents.len() as u32) {
+                    let rounds: Vec<Round> = opponents
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, opponent)| {
+                            let user = Shape(play / 5usize.pow(idx as u32) % 5);
+                            Round { user, res: rules.result(*opponent, user) }
+                        })
+                        .collect();
+                    let allowed = constraints.iter().all(|constraint| {
+                        let used = rounds.iter().filter(|round| constraint.counts(round)).count();
+                        used < constraint.max + 1 && (!constraint.exact || used == constraint.max)
+                    });
+                    if allowed {
+                        brute = brute.max(Some(rounds.iter().map(|round| round.score(&rules)).sum()));
+                    }
+                }
+
+                let plan = optimize(&rules, &opponents, &constraints).unwrap();
+                let replayed = plan.as_ref().map(|plan| {
+                    opponents
+                        .iter()
+                        .zip(&plan.shapes)
+                        .map(|(opponent, user)| Round { user: *user, res: rules.result(*opponent, *user) }.score(&rules))
+                        .sum::<u64>()
+                });
+                plan.map(|plan| plan.score) == brute && replayed == brute
+            },
+        );
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(Rules::cyclic(&["Rock", "Paper"]).is_err());
+        assert!(Rules::by_name("rpsx").is_err());
+
+        let shape = |name: &str, abc, xyz, beats: Vec<usize>| ShapeRule { name: name.to_owned(), score: 1, abc, xyz, beats };
+        let both_win = vec![shape("Rock", 'A', 'X', vec![1]), shape("Paper", 'B', 'Y', vec![0])];
+        assert!(Rules::new(both_win, ['X', 'Y', 'Z']).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, Result},
+};
+
+use aoc::{bench, cli, input, bitset::BitSet, years};
+
+const DAY: &'static str = "03";
+
+fn priority(c: char) -> u64 {
+    match c {
+        min if min >= 'a' && min <= 'z' => 1_u64 + min as u64 - 'a' as u64,
+        max if max >= 'A' && max <= 'Z' => 27_u64 + max as u64 - 'A' as u64,
+        _ => unreachable!("Not alphabetic"),
+    }
+}
+
+fn item(priority: usize) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+fn list(items: &[char]) -> String {
+    items.iter().map(|item| item.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+/// Something off in the input, with 1-based line numbers.
+#[derive(Debug, PartialEq)]
+enum Anomaly {
+    /// Rucksack that can't be split in two compartments of the same size.
+    OddLength { line: usize, len: usize },
+    NotAnItem { line: usize, item: char },
+    /// None or several items in both compartments.
+    SharedItems { line: usize, items: Vec<char> },
+    /// None or several items carried by the whole group.
+    Badges { lines: Vec<usize>, items: Vec<char> },
+    /// Last group, short of rucksacks.
+    IncompleteGroup { lines: Vec<usize> },
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = |lines: &[usize]| match lines {
+            [first, .., last] => format!("lines {}-{}", first, last),
+            _ => format!("line {}", lines[0]),
+        };
+        match self {
+            Anomaly::OddLength { line, len } => write!(f, "line {}: odd number of items ({})", line, len),
+            Anomaly::NotAnItem { line, item } => write!(f, "line {}: {:?} is not an item", line, item),
+            Anomaly::SharedItems { line, items } if items.is_empty() => {
+                write!(f, "line {}: no item in both compartments", line)
+            }
+            Anomaly::SharedItems { line, items } => {
+                write!(f, "line {}: several items in both compartments ({})", line, list(items))
+            }
+            Anomaly::Badges { lines: group, items } if items.is_empty() => write!(f, "{}: no badge", lines(group)),
+            Anomaly::Badges { lines: group, items } => {
+                write!(f, "{}: several badges ({})", lines(group), list(items))
+            }
+            Anomaly::IncompleteGroup { lines: group } => {
+                write!(f, "{}: incomplete group of {}", lines(group), group.len())
+            }
+        }
+    }
+}
+
+struct Rucksack {
+    line: usize,
+    left: BitSet,
+    right: BitSet,
+    /// Item in both compartments, if there is exactly one.
+    shared: Option<char>,
+}
+
+struct Group {
+    lines: Vec<usize>,
+    /// Item carried by every rucksack, if there is exactly one.
+    badge: Option<char>,
+}
+
+/// Rucksacks checked against the puzzle's rules, in groups of `group_size`.
+struct Rucksacks {
+    rucksacks: Vec<Rucksack>,
+    groups: Vec<Group>,
+    anomalies: Vec<Anomaly>,
+}
+
+impl Rucksacks {
+    /// Blank lines are skipped; items that are not letters are reported and
+    /// left out.
+    fn check(data: &str, group_size: usize) -> Self {
+        let mut anomalies = vec![];
+
+        let rucksacks: Vec<Rucksack> = data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| {
+                let line_number = idx + 1;
+                let chars: Vec<char> = line.chars().collect();
+                if chars.len() % 2 == 1 {
+                    anomalies.push(Anomaly::OddLength { line: line_number, len: chars.len() });
+                }
+                let mut compartments = chars.chunks(chars.len().div_ceil(2)).map(|half| {
+                    half.iter()
+                        .filter(|c| {
+                            let valid = c.is_ascii_alphabetic();
+                            if !valid {
+                                anomalies.push(Anomaly::NotAnItem { line: line_number, item: **c });
+                            }
+                            valid
+                        })
+                        .map(|c| priority(*c) as usize)
+                        .collect::<BitSet>()
+                });
+                let (left, right) = (compartments.next().unwrap_or_default(), compartments.next().unwrap_or_default());
+
+                let items: Vec<char> = (&left & &right).iter().map(item).collect();
+                let shared = match items[..] {
+                    [shared] => Some(shared),
+                    _ => {
+                        anomalies.push(Anomaly::SharedItems { line: line_number, items });
+                        None
+                    }
+                };
+                Rucksack { line: line_number, left, right, shared }
+            })
+            .collect();
+
+        let groups = rucksacks
+            .chunks(group_size)
+            .map(|group| {
+                let lines: Vec<usize> = group.iter().map(|rucksack| rucksack.line).collect();
+                if group.len() < group_size {
+                    anomalies.push(Anomaly::IncompleteGroup { lines });
+                    return Group { lines: group.iter().map(|rucksack| rucksack.line).collect(), badge: None };
+                }
+
+                let common = group
+                    .iter()
+                    .map(|rucksack| &rucksack.left | &rucksack.right)
+                    .reduce(|common, items| &common & &items)
+                    .unwrap_or_default();
+                let items: Vec<char> = common.iter().map(item).collect();
+                let badge = match items[..] {
+                    [badge] => Some(badge),
+                    _ => {
+                        anomalies.push(Anomaly::Badges { lines: lines.clone(), items });
+                        None
+                    }
+                };
+                Group { lines, badge }
+            })
+            .collect();
+
+        anomalies.sort_by_key(|anomaly| match anomaly {
+            Anomaly::OddLength { line, .. } | Anomaly::NotAnItem { line, .. } | Anomaly::SharedItems { line, .. } => *line,
+            Anomaly::Badges { lines, .. } | Anomaly::IncompleteGroup { lines } => lines[lines.len() - 1],
+        });
+        Rucksacks { rucksacks, groups, anomalies }
+    }
+
+    /// Priorities of the items found in both compartments.
+    fn shared_priorities(&self) -> u64 {
+        self.rucksacks.iter().filter_map(|rucksack| rucksack.shared).map(priority).sum()
+    }
+
+    fn badge_priorities(&self) -> u64 {
+        self.groups.iter().filter_map(|group| group.badge).map(priority).sum()
+    }
+}
+
+/// Items found in several rucksacks or groups count for none of them: the
+/// anomalies say why.
+fn part1(data: &str) -> u64 {
+    Rucksacks::check(data, 3).shared_priorities()
+}
+
+fn part2(data: &str, group_size: usize) -> u64 {
+    Rucksacks::check(data, group_size).badge_priorities()
+}
+
+fn main() -> Result<()> {
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let group_size = match cli::value("--group") {
+        Some(size) => size
+            .parse::<usize>()
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid --group: {}", size)))?,
+        None => 3,
+    };
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day03.test");
+        println!("Test :: Part 1 ====>     {}", part1(test_data));
+        println!("Test :: Part 2 ====>     {}", part2(test_data, group_size));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day03.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(input_data, group_size)));
+    }
+    println!("--------------------------------------------------------");
+
+    let rucksacks = Rucksacks::check(input_data, group_size);
+    if cli::flag("--badges") {
+        rucksacks.groups.iter().for_each(|group| {
+            let badge = group.badge.map_or("-".to_owned(), |badge| badge.to_string());
+            println!("Badge:: lines {:>4}-{:<4} {}", group.lines[0], group.lines[group.lines.len() - 1], badge);
+        });
+    }
+    rucksacks.anomalies.iter().for_each(|anomaly| eprintln!("Anomaly:: {}", anomaly));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn to_bitset(s: &str) -> BitSet {
+        s.chars()
+            .map(|c| priority(c) as usize)
+            .collect()
+    }
+
+    const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    #[test]
+    fn priorities_are_one_to_fifty_two() {
+        prop::check(
+            "priorities are distinct and in 1..=52",
+            |rng| rng.string(2, ITEMS),
+            |pair| {
+                let chars: Vec<char> = pair.chars().collect();
+                match chars[..] {
+                    [a, b] => {
+                        (1..=52).contains(&priority(a)) && (a == b) == (priority(a) == priority(b))
+                    }
+                    _ => true,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn shared_items_are_in_both_compartments() {
+        prop::check(
+            "bitset intersection matches shared chars",
+            |rng| (rng.string(12, ITEMS), rng.string(12, ITEMS)),
+            |(left, right)| {
+                let shared: BitSet = left
+                    .chars()
+                    .filter(|c| right.contains(*c))
+                    .map(|c| priority(c) as usize)
+                    .collect();
+                &to_bitset(left) & &to_bitset(right) == shared
+            },
+        );
+    }
+
+    #[test]
+    fn example_has_no_anomaly() {
+        let rucksacks = Rucksacks::check(input!("day03.test"), 3);
+        assert!(rucksacks.anomalies.is_empty());
+        let badges: Vec<Option<char>> = rucksacks.groups.iter().map(|group| group.badge).collect();
+        assert_eq!(badges, vec![Some('r'), Some('Z')]);
+        assert_eq!((rucksacks.shared_priorities(), rucksacks.badge_priorities()), (157, 70));
+    }
+
+    #[test]
+    fn anomalies_are_listed() {
+        let rucksacks = Rucksacks::check("abca\nab1b\n\nabcab\nxyzw\nxa\n", 2);
+        assert_eq!(
+            rucksacks.anomalies,
+            vec![
+                Anomaly::NotAnItem { line: 2, item: '1' },
+                Anomaly::Badges { lines: vec![1, 2], items: vec!['a', 'b'] },
+                Anomaly::OddLength { line: 4, len: 5 },
+                Anomaly::SharedItems { line: 4, items: vec!['a', 'b'] },
+                Anomaly::SharedItems { line: 5, items: vec![] },
+                Anomaly::Badges { lines: vec![4, 5], items: vec![] },
+                Anomaly::SharedItems { line: 6, items: vec![] },
+                Anomaly::IncompleteGroup { lines: vec![6] },
+            ]
+        );
+        assert_eq!(rucksacks.shared_priorities(), priority('a') + priority('b'));
+        assert_eq!(Anomaly::Badges { lines: vec![4, 5], items: vec![] }.to_string(), "lines 4-5: no badge");
+    }
+}
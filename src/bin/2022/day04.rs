@@ -0,0 +1,445 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use aoc::{bench, cli, input, years};
+
+const DAY: &'static str = "04";
+
+/// Closed interval of section ids, `start <= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Section {
+    start: u64,
+    end: u64,
+}
+
+#[derive(Debug, PartialEq)]
+enum SectionError {
+    /// Not `START-END` or not `LEFT,RIGHT`.
+    Syntax(String),
+    Number(String, ParseIntError),
+    /// One elf of the pair without an assignment.
+    Empty,
+    Inverted { start: u64, end: u64 },
+}
+
+impl Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SectionError::Syntax(s) => write!(f, "invalid assignment {:?}", s),
+            SectionError::Number(s, e) => write!(f, "invalid section id {:?}: {}", s, e),
+            SectionError::Empty => write!(f, "empty assignment"),
+            SectionError::Inverted { start, end } => write!(f, "inverted bounds {}-{}", start, end),
+        }
+    }
+}
+
+impl Section {
+    /// As `u128`, `0-18446744073709551615` holding `u64::MAX + 1` sections.
+    fn len(&self) -> u128 {
+        (self.end - self.start) as u128 + 1
+    }
+
+    fn contains(&self, other: &Section) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    fn intersection(&self, other: &Section) -> Option<Section> {
+        let (start, end) = (self.start.max(other.start), self.end.min(other.end));
+        (start <= end).then_some(Section { start, end })
+    }
+
+    /// Both sections as one, if they overlap or touch.
+    fn union(&self, other: &Section) -> Option<Section> {
+        let (first, second) = if self.start <= other.start { (self, other) } else { (other, self) };
+        (second.start <= first.end.saturating_add(1)).then_some(Section {
+            start: first.start,
+            end: first.end.max(second.end),
+        })
+    }
+
+    fn overlap_len(&self, other: &Section) -> u128 {
+        self.intersection(other).map_or(0, |common| common.len())
+    }
+}
+
+impl FromStr for Section {
+    type Err = SectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(SectionError::Empty);
+        }
+        let (start, end) = s.split_once('-').ok_or_else(|| SectionError::Syntax(s.to_owned()))?;
+        let id = |id: &str| {
+            id.trim()
+                .parse::<u64>()
+                .map_err(|e| SectionError::Number(id.to_owned(), e))
+        };
+        let (start, end) = (id(start)?, id(end)?);
+        if start > end {
+            return Err(SectionError::Inverted { start, end });
+        }
+        Ok(Self { start, end })
+    }
+}
+
+/// Assignments of two elves, in the order of the input.
+#[derive(Debug)]
+struct Pair {
+    left: Section,
+    right: Section,
+}
+
+impl Pair {
+    fn is_full_overlaps(&self) -> bool {
+        self.left.contains(&self.right) || self.right.contains(&self.left)
+    }
+
+    fn is_overlaps(&self) -> bool {
+        self.left.intersection(&self.right).is_some()
+    }
+
+    fn overlap_len(&self) -> u128 {
+        self.left.overlap_len(&self.right)
+    }
+
+    /// Sections assigned to either elf.
+    fn covered_len(&self) -> u128 {
+        self.left
+            .union(&self.right)
+            .map_or(self.left.len() + self.right.len(), |union| union.len())
+    }
+}
+
+impl FromStr for Pair {
+    type Err = SectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s.split_once(',').ok_or_else(|| SectionError::Syntax(s.to_owned()))?;
+        Ok(Pair {
+            left: left.parse()?,
+            right: right.parse()?,
+        })
+    }
+}
+
+/// Valid pairs, and the errors of the others with their 1-based line.
+fn parse_pairs(data: &str) -> (Vec<Pair>, Vec<(usize, SectionError)>) {
+    let mut errors = vec![];
+    let pairs = data
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| line.parse::<Pair>().map_err(|e| errors.push((idx + 1, e))).ok())
+        .collect();
+    (pairs, errors)
+}
+
+fn part1(data: &str) -> usize {
+    parse_pairs(data).0
+        .iter()
+        .filter(|pair| pair.is_full_overlaps())
+        .count()
+}
+
+fn part2(data: &str) -> usize {
+    parse_pairs(data).0
+        .iter()
+        .filter(|pair| pair.is_overlaps())
+        .count()
+}
+
+/// Sections assigned twice in a pair, and sections assigned at all, over
+/// all the pairs.
+fn overlap_len(data: &str) -> (u128, u128) {
+    parse_pairs(data)
+        .0
+        .iter()
+        .fold((0, 0), |(overlap, covered), pair| (overlap + pair.overlap_len(), covered + pair.covered_len()))
+}
+
+/// How all the elves together cover the sections.
+#[derive(Debug, PartialEq)]
+struct Coverage {
+    /// First to last section assigned, `None` without assignment.
+    range: Option<Section>,
+    /// Most elves assigned to a single section...
+    depth: usize,
+    /// ...and where.
+    deepest: Vec<Section>,
+    /// Sections of `range` nobody is assigned to.
+    gaps: Vec<Section>,
+    /// Pairs of any two elves sharing a section.
+    overlapping_pairs: u64,
+}
+
+/// Extends the last section of `sections` with `section` if they touch.
+fn push_merged(sections: &mut Vec<Section>, section: Section) {
+    match sections.last_mut().and_then(|last| last.union(&section).map(|union| (last, union))) {
+        Some((last, union)) => *last = union,
+        None => sections.push(section),
+    }
+}
+
+/// Sweeps the bounds of the sections, in `O(n log n)`.
+fn coverage(sections: &[Section]) -> Coverage {
+    // Elves leaving at `end + 1` before the ones arriving there; wide enough
+    // for `u64::MAX + 1`.
+    let mut events: Vec<(u128, bool)> = sections
+        .iter()
+        .flat_map(|section| [(section.start as u128, true), (section.end as u128 + 1, false)])
+        .collect();
+    events.sort_unstable();
+
+    let mut coverage = Coverage {
+        range: None,
+        depth: 0,
+        deepest: vec![],
+        gaps: vec![],
+        overlapping_pairs: 0,
+    };
+    let mut active = 0;
+    for (idx, (position, start)) in events.iter().enumerate() {
+        if *start {
+            coverage.overlapping_pairs += active as u64;
+            active += 1;
+        } else {
+            active -= 1;
+        }
+
+        // Depth is `active` until the next event.
+        let Some((next, _)) = events.get(idx + 1).filter(|(next, _)| next > position) else {
+            continue;
+        };
+        let section = Section { start: *position as u64, end: (next - 1) as u64 };
+        if active == 0 {
+            push_merged(&mut coverage.gaps, section);
+        } else if active > coverage.depth {
+            coverage.depth = active;
+            coverage.deepest = vec![section];
+        } else if active == coverage.depth {
+            push_merged(&mut coverage.deepest, section);
+        }
+    }
+
+    coverage.range = sections.iter().map(|section| section.start).min().zip(
+        sections.iter().map(|section| section.end).max()
+    ).map(|(start, end)| Section { start, end });
+    coverage
+}
+
+fn print_coverage(data: &str) {
+    let sections: Vec<Section> = parse_pairs(data)
+        .0
+        .iter()
+        .flat_map(|pair| [pair.left, pair.right])
+        .collect();
+    let coverage = coverage(&sections);
+    let Some(range) = coverage.range else {
+        println!("Cover:: no assignment");
+        return;
+    };
+
+    let list = |sections: &[Section]| {
+        let mut res: Vec<String> = sections.iter().take(10).map(|s| format!("{}-{}", s.start, s.end)).collect();
+        if sections.len() > 10 {
+            res.push(format!("... {} more", sections.len() - 10));
+        }
+        if res.is_empty() { "none".to_owned() } else { res.join(", ") }
+    };
+    println!("Cover:: Elves ====>      {} over sections {}-{}", sections.len(), range.start, range.end);
+    println!("Cover:: Deepest ====>    {} elves on {}", coverage.depth, list(&coverage.deepest));
+    println!("Cover:: Gaps ====>       {}", list(&coverage.gaps));
+    println!("Cover:: Pairs ====>      {} overlapping", coverage.overlapping_pairs);
+    let (overlap, covered) = overlap_len(data);
+    println!("Cover:: Overlap ====>    {} of {} sections", overlap, covered);
+}
+
+fn main() -> io::Result<()> {
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day04.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day04.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if cli::flag("--coverage") {
+        print_coverage(input_data);
+    }
+    parse_pairs(input_data)
+        .1
+        .iter()
+        .for_each(|(line, e)| eprintln!("Error:: line {}: {}", line, e));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    /// Start and length rather than bounds, so that shrinking keeps
+    /// `start <= end`.
+    fn section(rng: &mut prop::Rng) -> (u64, u64) {
+        (rng.below(20), rng.below(20))
+    }
+
+    fn bounds((start, len): (u64, u64)) -> (u64, u64) {
+        (start, start + len)
+    }
+
+    fn pair((left, right): &((u64, u64), (u64, u64))) -> Pair {
+        let ((a, b), (c, d)) = (bounds(*left), bounds(*right));
+        format!("{}-{},{}-{}", a, b, c, d).parse().unwrap()
+    }
+
+    #[test]
+    fn overlap_is_symmetric() {
+        prop::check(
+            "swapping the elves keeps the overlaps",
+            |rng| (section(rng), section(rng)),
+            |sections| {
+                let swapped = (sections.1, sections.0);
+                pair(sections).is_overlaps() == pair(&swapped).is_overlaps()
+                    && pair(sections).is_full_overlaps() == pair(&swapped).is_full_overlaps()
+            },
+        );
+    }
+
+    #[test]
+    fn overlap_matches_sections_content() {
+        prop::check(
+            "overlaps agree with the sections ids",
+            |rng| (section(rng), section(rng)),
+            |sections| {
+                let ((a, b), (c, d)) = (bounds(sections.0), bounds(sections.1));
+                let left: Vec<u64> = (a..=b).collect();
+                let right: Vec<u64> = (c..=d).collect();
+                let common = left.iter().filter(|id| right.contains(id)).count();
+
+                pair(sections).is_overlaps() == (common > 0)
+                    && pair(sections).is_full_overlaps() == (common == left.len() || common == right.len())
+            },
+        );
+    }
+
+    #[test]
+    fn interval_algebra() {
+        prop::check(
+            "intersection, union and containment agree with the sections ids",
+            |rng| (section(rng), section(rng)),
+            |(left, right)| {
+                let ((a, b), (c, d)) = (&bounds(*left), &bounds(*right));
+                let (left, right) = (Section { start: *a, end: *b }, Section { start: *c, end: *d });
+                let ids = |section: Option<Section>| -> Vec<u64> { section.map_or(vec![], |s| (s.start..=s.end).collect()) };
+                let common: Vec<u64> = (*a..=*b).filter(|id| (*c..=*d).contains(id)).collect();
+                let mut all: Vec<u64> = (*a..=*b).chain(*c..=*d).collect();
+                all.sort();
+                all.dedup();
+                let contiguous = all.windows(2).all(|ids| ids[1] == ids[0] + 1);
+
+                ids(left.intersection(&right)) == common
+                    && left.overlap_len(&right) == common.len() as u128
+                    && left.contains(&right) == (common.len() as u128 == right.len())
+                    && right.contains(&left) == (common.len() as u128 == left.len())
+                    && left.union(&right) == right.union(&left)
+                    && (ids(left.union(&right)) == all) == contiguous
+                    && left.union(&right).is_some() == contiguous
+            },
+        );
+    }
+
+    #[test]
+    fn invalid_pairs_are_errors() {
+        let (pairs, errors) = parse_pairs("2-4,6-8\n,3-5\n5-3,1-2\n1-2\n1-x,3-4\n2-6,4-8\n");
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs.iter().map(|pair| pair.overlap_len()).sum::<u128>(), 3);
+        assert_eq!(pairs.iter().map(|pair| pair.covered_len()).sum::<u128>(), 6 + 7);
+
+        let errors: Vec<(usize, String)> = errors.iter().map(|(line, e)| (*line, e.to_string())).collect();
+        assert_eq!(
+            errors,
+            vec![
+                (2, "empty assignment".to_owned()),
+                (3, "inverted bounds 5-3".to_owned()),
+                (4, "invalid assignment \"1-2\"".to_owned()),
+                (5, "invalid section id \"x\": invalid digit found in string".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn coverage_matches_counting() {
+        prop::check(
+            "the sweep agrees with counting the elves on each section",
+            |rng| rng.vec(8, section),
+            |sections| {
+                let sections: Vec<Section> = sections
+                    .iter()
+                    .map(|section| bounds(*section))
+                    .map(|(start, end)| Section { start, end })
+                    .collect();
+                let coverage = coverage(&sections);
+                let Some(range) = coverage.range else {
+                    return sections.is_empty();
+                };
+
+                let depths: Vec<(u64, usize)> = (range.start..=range.end)
+                    .map(|id| (id, sections.iter().filter(|s| s.start <= id && id <= s.end).count()))
+                    .collect();
+                let depth = depths.iter().map(|(_, depth)| *depth).max().unwrap();
+                let ids = |sections: &[Section]| -> Vec<u64> { sections.iter().flat_map(|s| s.start..=s.end).collect() };
+                let pairs = (0..sections.len())
+                    .flat_map(|i| (i + 1..sections.len()).map(move |j| (i, j)))
+                    .filter(|(i, j)| sections[*i].intersection(&sections[*j]).is_some())
+                    .count();
+
+                coverage.depth == depth
+                    && ids(&coverage.deepest) == depths.iter().filter(|(_, d)| *d == depth).map(|(id, _)| *id).collect::<Vec<u64>>()
+                    && ids(&coverage.gaps) == depths.iter().filter(|(_, d)| *d == 0).map(|(id, _)| *id).collect::<Vec<u64>>()
+                    && coverage.deepest.windows(2).all(|w| w[0].end + 1 < w[1].start)
+                    && coverage.overlapping_pairs == pairs as u64
+            },
+        );
+    }
+
+    #[test]
+    fn coverage_up_to_the_last_id() {
+        let last = Section { start: u64::MAX - 1, end: u64::MAX };
+        let coverage = coverage(&[Section { start: 1, end: 2 }, last, last]);
+        assert_eq!(coverage.depth, 2);
+        assert_eq!(coverage.deepest, vec![last]);
+        assert_eq!(coverage.gaps, vec![Section { start: 3, end: u64::MAX - 2 }]);
+        assert_eq!(coverage.overlapping_pairs, 1);
+    }
+
+    #[test]
+    fn lengths_of_the_whole_range() {
+        let pair: Pair = "0-18446744073709551615,0-18446744073709551615".parse().unwrap();
+        assert_eq!(pair.left.len(), 1 << 64);
+        assert_eq!((pair.overlap_len(), pair.covered_len()), (1 << 64, 1 << 64));
+
+        let pair: Pair = "0-18446744073709551614,18446744073709551615-18446744073709551615".parse().unwrap();
+        assert_eq!((pair.overlap_len(), pair.covered_len()), (0, 1 << 64));
+    }
+}
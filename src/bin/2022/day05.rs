@@ -0,0 +1,703 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use aoc::{
+    animation::{Animation, Player},
+    bench,
+    cli,
+    input,
+    repl::{self, Session},
+    years,
+};
+
+const DAY: &'static str = "05";
+
+#[derive(Clone)]
+struct Crates {
+    size: usize,
+    crates: Vec<Vec<char>>,
+}
+
+impl Crates {
+    /// Whether `action` can be done from the current stacks. The moves below
+    /// expect checked actions.
+    fn check(&self, action: &Action) -> Result<(), MoveError> {
+        if let Some(stack) = [action.from, action.to].into_iter().find(|id| *id == 0 || *id > self.size) {
+            return Err(MoveError::NoStack { stack, size: self.size });
+        }
+        if action.from == action.to {
+            return Err(MoveError::SameStack(action.from));
+        }
+        let available = self.crates[action.from - 1].len();
+        if available < action.size {
+            return Err(MoveError::NotEnoughCrates { stack: action.from, wanted: action.size, available });
+        }
+        Ok(())
+    }
+
+    fn move_action(&mut self, from: usize, to: usize) {
+        if let Some(value) = self.crates[from - 1].pop() {
+            self.crates[to - 1].push(value);
+        }
+    }
+
+    /// Removes the top `count` crates of `from`, bottom first.
+    fn take(&mut self, from: usize, count: usize) -> Vec<char> {
+        let from: &mut Vec<char> = self.crates.get_mut(from - 1).unwrap();
+        let end: usize = from.len();
+        let start: usize = end - count;
+        from.drain(start..end).collect()
+    }
+
+    fn put(&mut self, to: usize, values: &[char]) {
+        let to: &mut Vec<char> = self.crates.get_mut(to - 1).unwrap();
+        values.iter().for_each(|c| to.push(*c))
+    }
+
+    fn top_crates(&self) -> String {
+        self.crates
+            .iter()
+            .map(|stack| {
+                if stack.len() > 0 {
+                    Some(stack.last().unwrap())
+                } else {
+                    None
+                }
+            })
+            .filter(|top| *top != None)
+            .map(|c| c.unwrap())
+            .collect()
+    }
+
+    fn draw(&self) -> String {
+        let height = self.crates.iter().map(|stack| stack.len()).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                self.crates
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(value) => format!("[{}]", value),
+                        None => "   ".to_owned(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        lines.push(
+            (1..=self.size)
+                .map(|id| format!(" {} ", id))
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+        lines.join("\n")
+    }
+}
+
+impl FromStr for Crates {
+    type Err = StacksError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data_lines: Vec<&str> = s.lines().collect();
+
+        let Some((numbers, lines)) = data_lines.split_last() else {
+            return Err(StacksError::Empty);
+        };
+        let size: usize = numbers
+            .split_whitespace()
+            .last()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| StacksError::Header(numbers.to_string()))?;
+
+        let mut crates: Vec<Vec<char>> = vec![vec![]; size];
+
+        let mut lines: Vec<&str> = Vec::from(lines);
+        lines.reverse();
+        lines.iter().for_each(|line| {
+            for i in 0..size {
+                // Rows may lose their trailing blanks to an editor.
+                let value: char = line.chars().nth(4 * i + 1).unwrap_or(' ');
+                if value != ' ' {
+                    crates.get_mut(i).unwrap().push(value);
+                }
+            }
+        });
+
+        Ok(Crates { size, crates })
+    }
+}
+
+/// Drawing of the stacks that can't be read.
+#[derive(Debug, PartialEq)]
+enum StacksError {
+    Empty,
+    /// The last line, expected to number the stacks.
+    Header(String),
+}
+
+impl Display for StacksError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StacksError::Empty => write!(f, "no stacks drawn"),
+            StacksError::Header(line) => write!(f, "{:?} does not number the stacks", line),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Action {
+    size: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Action {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nums: Vec<&str> = s
+            .split(" ")
+            .filter(|w| w.trim().chars().all(|c| c.is_numeric()))
+            .collect();
+
+        let to: usize = nums.pop().unwrap_or_default().parse()?;
+        let from: usize = nums.pop().unwrap_or_default().parse()?;
+        let size: usize = nums.pop().unwrap_or_default().parse()?;
+
+        Ok(Action { size, from, to })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum MoveError {
+    Syntax(ParseIntError),
+    NoStack { stack: usize, size: usize },
+    SameStack(usize),
+    NotEnoughCrates { stack: usize, wanted: usize, available: usize },
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::Syntax(e) => write!(f, "not a move: {}", e),
+            MoveError::NoStack { stack, size } => write!(f, "no stack {}, stacks are 1 to {}", stack, size),
+            MoveError::SameStack(stack) => write!(f, "moving from stack {} to itself", stack),
+            MoveError::NotEnoughCrates { stack, wanted, available } => {
+                write!(f, "{} crates wanted from stack {}, only {} there", wanted, stack, available)
+            }
+        }
+    }
+}
+
+/// An action that can't be done, with the stacks it was tried on.
+#[derive(Debug)]
+struct ActionError {
+    /// 1-based line in the input.
+    line: usize,
+    text: String,
+    error: MoveError,
+    stacks: String,
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {:?}: {}\n{}", self.line, self.text, self.error, self.stacks)
+    }
+}
+
+/// Why the input can't be simulated.
+#[derive(Debug)]
+enum InputError {
+    Stacks(StacksError),
+    Action(ActionError),
+}
+
+impl From<ActionError> for InputError {
+    fn from(e: ActionError) -> Self {
+        InputError::Action(e)
+    }
+}
+
+/// Prints `e` in full, the stacks being unreadable in an `io::Error`.
+fn report(e: InputError) -> io::Error {
+    let msg = match e {
+        InputError::Stacks(e) => format!("invalid stacks: {}", e),
+        InputError::Action(e) => {
+            eprintln!("Error:: {}", e);
+            format!("invalid move on line {}", e.line)
+        }
+    };
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// How a crane carries the crates of an action.
+trait CraneModel {
+    fn name(&self) -> String;
+
+    fn apply(&self, crates: &mut Crates, action: &Action);
+}
+
+/// One crate at a time, as in part 1.
+struct CrateMover9000;
+
+/// All the crates of the action at once, as in part 2.
+struct CrateMover9001;
+
+/// Up to `k` crates at a time, keeping their order: `Batches(1)` is the
+/// 9000, `k` over the action size the 9001.
+struct Batches(usize);
+
+/// All the crates at once, each batch of `k` from the top upside down:
+/// `ReversedBatches(1)` is the 9001, `k` over the action size the 9000.
+struct ReversedBatches(usize);
+
+impl CraneModel for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_owned()
+    }
+
+    fn apply(&self, crates: &mut Crates, action: &Action) {
+        for _ in 0..(action.size) {
+            crates.move_action(action.from, action.to);
+        }
+    }
+}
+
+impl CraneModel for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_owned()
+    }
+
+    fn apply(&self, crates: &mut Crates, action: &Action) {
+        let values = crates.take(action.from, action.size);
+        crates.put(action.to, &values);
+    }
+}
+
+impl CraneModel for Batches {
+    fn name(&self) -> String {
+        format!("batches of {}", self.0)
+    }
+
+    fn apply(&self, crates: &mut Crates, action: &Action) {
+        let mut left = action.size;
+        while left > 0 {
+            let values = crates.take(action.from, left.min(self.0));
+            crates.put(action.to, &values);
+            left -= values.len();
+        }
+    }
+}
+
+impl CraneModel for ReversedBatches {
+    fn name(&self) -> String {
+        format!("reversed batches of {}", self.0)
+    }
+
+    fn apply(&self, crates: &mut Crates, action: &Action) {
+        let values = crates.take(action.from, action.size);
+        let flipped: Vec<char> = values.rchunks(self.0).rev().flat_map(|batch| batch.iter().rev()).copied().collect();
+        crates.put(action.to, &flipped);
+    }
+}
+
+/// `9000`, `9001`, `batch:K` or `reversed:K`.
+fn crane(name: &str) -> Result<Box<dyn CraneModel>, String> {
+    let batch = |k: &str| {
+        k.parse::<usize>()
+            .ok()
+            .filter(|k| *k > 0)
+            .ok_or_else(|| format!("Invalid batch size: {}", k))
+    };
+    match name.split_once(':') {
+        None if name == "9000" => Ok(Box::new(CrateMover9000)),
+        None if name == "9001" => Ok(Box::new(CrateMover9001)),
+        Some(("batch", k)) => Ok(Box::new(Batches(batch(k)?))),
+        Some(("reversed", k)) => Ok(Box::new(ReversedBatches(batch(k)?))),
+        _ => Err(format!("Unknown crane: {} (9000, 9001, batch:K or reversed:K)", name)),
+    }
+}
+
+#[derive(Clone)]
+struct CratesAnimation {
+    crates: Crates,
+    actions: Vec<Action>,
+    action_idx: usize,
+    moved: usize,
+}
+
+impl CratesAnimation {
+    /// Checked by [`simulate`], so that no invalid action is ever animated.
+    fn new(data: &str) -> Result<Self, InputError> {
+        let simulation = simulate(data, &CrateMover9000, false)?;
+        Ok(Self {
+            crates: simulation.start,
+            actions: simulation.actions,
+            action_idx: 0,
+            moved: 0,
+        })
+    }
+}
+
+impl Animation for CratesAnimation {
+    fn title(&self) -> String {
+        let current = match self.actions.get(self.action_idx) {
+            Some(action) => format!(
+                "move {} from {} to {} ({}/{})",
+                action.size, action.from, action.to, self.moved, action.size
+            ),
+            None => "done".to_owned(),
+        };
+        format!(
+            "AOC{} - Day {} :: action {}/{} {} :: top {}",
+            years::DEFAULT_YEAR,
+            DAY,
+            self.action_idx.min(self.actions.len()),
+            self.actions.len(),
+            current,
+            self.crates.top_crates()
+        )
+    }
+
+    fn frame(&self) -> String {
+        self.crates.draw()
+    }
+
+    // One crate at a time, as `CrateMover9000` does.
+    fn advance(&mut self) -> bool {
+        while self.actions.get(self.action_idx).map(|a| a.size) == Some(0) {
+            self.action_idx += 1;
+        }
+        let Some(action) = self.actions.get(self.action_idx) else {
+            return false;
+        };
+
+        // Invalid actions are passed over.
+        if self.moved == 0 && self.crates.check(action).is_err() {
+            self.action_idx += 1;
+            return true;
+        }
+        self.crates.move_action(action.from, action.to);
+        self.moved += 1;
+        if self.moved == action.size {
+            self.action_idx += 1;
+            self.moved = 0;
+        }
+        true
+    }
+}
+
+/// Whole actions at a time, on top of the animation model.
+struct CratesSession(CratesAnimation);
+
+impl Session for CratesSession {
+    fn step(&mut self, n: usize) -> Result<String, String> {
+        let animation = &mut self.0;
+        if animation.action_idx >= animation.actions.len() {
+            return Err(format!("All {} actions applied", animation.actions.len()));
+        }
+
+        let mut steps = vec![];
+        for _ in 0..n {
+            let Some(action) = animation.actions.get(animation.action_idx) else {
+                break;
+            };
+            steps.push(format!(
+                "{:>4}: move {} from {} to {}",
+                animation.action_idx + 1,
+                action.size,
+                action.from,
+                action.to
+            ));
+            let idx = animation.action_idx;
+            while animation.action_idx == idx && animation.advance() {}
+        }
+        let mut res = repl::last_steps(steps);
+        res.push(format!("top {}", animation.crates.top_crates()));
+        Ok(res.join("\n"))
+    }
+
+    fn show(&self) -> String {
+        format!("{}\n{}", self.0.title(), self.0.frame())
+    }
+
+    fn query(&self, args: &[&str]) -> Result<String, String> {
+        let animation = &self.0;
+        match args {
+            ["top"] => Ok(animation.crates.top_crates()),
+            ["stack", id] => id
+                .parse::<usize>()
+                .ok()
+                .and_then(|id| animation.crates.crates.get(id.checked_sub(1)?))
+                .map(|stack| stack.iter().collect())
+                .ok_or_else(|| format!("No stack {}", id)),
+            ["next"] => animation
+                .actions
+                .get(animation.action_idx)
+                .map(|action| format!("move {} from {} to {}", action.size, action.from, action.to))
+                .ok_or_else(|| "No action left".to_owned()),
+            _ => Err(format!("Unknown query: {}", args.join(" "))),
+        }
+    }
+
+    fn query_help(&self) -> Vec<String> {
+        vec![
+            "top            crates on top of the stacks".to_owned(),
+            "stack N        crates of stack N, bottom first".to_owned(),
+            "next           next action".to_owned(),
+        ]
+    }
+}
+
+/// Run of the actions of an input.
+struct Simulation {
+    start: Crates,
+    /// The valid actions, in order.
+    actions: Vec<Action>,
+    end: Crates,
+    skipped: Vec<ActionError>,
+}
+
+/// Runs the actions of `data` with `crane`, stopping at the first invalid
+/// one, or skipping them all when `lenient`.
+fn simulate(data: &str, crane: &dyn CraneModel, lenient: bool) -> Result<Simulation, InputError> {
+    let data_split = data.split("\n\n").collect::<Vec<&str>>();
+
+    let start: Crates = data_split[0].parse().map_err(InputError::Stacks)?;
+    let mut crates = start.clone();
+    let first_line = data_split[0].lines().count() + 2;
+
+    let mut actions = vec![];
+    let mut skipped = vec![];
+    for (idx, line) in data_split.get(1).copied().unwrap_or_default().lines().enumerate() {
+        let checked = line
+            .parse::<Action>()
+            .map_err(MoveError::Syntax)
+            .and_then(|action| crates.check(&action).map(|_| action));
+        match checked {
+            Ok(action) => {
+                crane.apply(&mut crates, &action);
+                actions.push(action);
+            }
+            Err(error) => {
+                let error = ActionError {
+                    line: first_line + idx,
+                    text: line.to_owned(),
+                    error,
+                    stacks: crates.draw(),
+                };
+                if !lenient {
+                    return Err(error.into());
+                }
+                skipped.push(error);
+            }
+        }
+    }
+    Ok(Simulation {
+        start,
+        actions,
+        end: crates,
+        skipped,
+    })
+}
+
+fn top_crates(data: &str, crane: &dyn CraneModel, lenient: bool) -> Result<String, InputError> {
+    simulate(data, crane, lenient).map(|simulation| simulation.end.top_crates())
+}
+
+fn part1(data: &str, lenient: bool) -> Result<String, InputError> {
+    top_crates(data, &CrateMover9000, lenient)
+}
+
+fn part2(data: &str, lenient: bool) -> Result<String, InputError> {
+    top_crates(data, &CrateMover9001, lenient)
+}
+
+fn main() -> io::Result<()> {
+    if cli::flag("--animate") {
+        let data = match cli::value("--animate").as_deref() {
+            Some("test") => input!("day05.test"),
+            _ => input!("day05.in"),
+        };
+        return Player::from_cli().play(&mut CratesAnimation::new(data).map_err(report)?);
+    }
+    if cli::flag("--repl") {
+        let data = match cli::value("--repl").as_deref() {
+            Some("test") => input!("day05.test").to_owned(),
+            _ => cli::input()?.unwrap_or_else(|| input!("day05.in").to_owned()),
+        };
+        let animation = CratesAnimation::new(&data).map_err(report)?;
+        return repl::run("day05", || Ok(CratesSession(animation.clone())));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let lenient = cli::flag("--lenient");
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day05.test");
+        println!("Test :: Part 1 ====>     {}", part1(test_data, lenient).map_err(report)?);
+        println!("Test :: Part 2 ====>     {}", part2(test_data, lenient).map_err(report)?);
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day05.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(input_data, lenient)).map_err(report)?);
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(input_data, lenient)).map_err(report)?);
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(name) = cli::value("--crane") {
+        let crane = crane(&name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        println!("Crane:: {} ====>  {}", crane.name(), top_crates(input_data, crane.as_ref(), lenient).map_err(report)?);
+    }
+    if lenient {
+        let simulation = simulate(input_data, &CrateMover9000, true).map_err(report)?;
+        simulation.skipped.iter().for_each(|e| eprintln!("Skipped:: {}", e));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    const CRATES: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    fn stacks(rng: &mut prop::Rng) -> Vec<String> {
+        let size = 1 + rng.below(9) as usize;
+        (0..size).map(|_| rng.string(6, CRATES)).collect()
+    }
+
+    #[test]
+    fn draw_parse_round_trip() {
+        prop::check(
+            "drawn crates parse back to the same stacks",
+            stacks,
+            |stacks| {
+                if stacks.is_empty() {
+                    return true;
+                }
+                let crates = Crates {
+                    size: stacks.len(),
+                    crates: stacks.iter().map(|stack| stack.chars().collect()).collect(),
+                };
+                let parsed = crates.draw().parse::<Crates>().unwrap();
+                parsed.size == crates.size && parsed.crates == crates.crates
+            },
+        );
+    }
+
+    #[test]
+    fn action_round_trip() {
+        prop::check(
+            "move lines parse back",
+            |rng| {
+                (
+                    rng.below(50) as usize,
+                    rng.below(10) as usize,
+                    rng.below(10) as usize,
+                )
+            },
+            |&(size, from, to)| {
+                let action = format!("move {} from {} to {}", size, from, to)
+                    .parse::<Action>()
+                    .unwrap();
+                (action.size, action.from, action.to) == (size, from, to)
+            },
+        );
+    }
+
+    #[test]
+    fn crane_models_agree_on_single_moves() {
+        prop::check(
+            "moving one crate at a time or at once is the same for size 1",
+            |rng| (stacks(rng), rng.below(9) as usize, rng.below(9) as usize),
+            |(stacks, from, to)| {
+                let (from, to) = (from + 1, to + 1);
+                if stacks.is_empty()
+                    || from > stacks.len()
+                    || to > stacks.len()
+                    || stacks[from - 1].is_empty()
+                {
+                    return true;
+                }
+                let crates = || Crates {
+                    size: stacks.len(),
+                    crates: stacks.iter().map(|stack| stack.chars().collect()).collect(),
+                };
+                let (mut one_by_one, mut at_once) = (crates(), crates());
+                CrateMover9000.apply(&mut one_by_one, &Action { size: 1, from, to });
+                CrateMover9001.apply(&mut at_once, &Action { size: 1, from, to });
+                one_by_one.crates == at_once.crates
+            },
+        );
+    }
+
+    #[test]
+    fn batch_models_bound_the_movers() {
+        let data = input!("day05.test");
+        let top = |name: &str| top_crates(data, crane(name).unwrap().as_ref(), false).unwrap();
+
+        assert_eq!(top("9000"), "CMZ");
+        assert_eq!(top("9001"), "MCD");
+        assert_eq!(top("batch:1"), top("9000"));
+        assert_eq!(top("reversed:3"), top("9000"));
+        assert_eq!(top("batch:3"), top("9001"));
+        assert_eq!(top("reversed:1"), top("9001"));
+        assert_eq!(top("batch:2"), "MCZ");
+        assert_eq!(top("reversed:2"), "CMN");
+        assert!(crane("batch:0").is_err());
+        assert!(crane("9002").is_err());
+    }
+
+    #[test]
+    fn invalid_moves_are_errors() {
+        let data = "[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 1 to 3\nmove 1 from 2 to 2\nmove 3 from 2 to 1\nmove x\nmove 1 from 2 to 1\n";
+        let Some(InputError::Action(error)) = simulate(data, &CrateMover9001, false).err() else {
+            panic!("Expected an invalid move");
+        };
+        assert_eq!((error.line, error.error), (5, MoveError::NoStack { stack: 3, size: 2 }));
+        assert_eq!(error.stacks, "[A]    \n[B] [C]\n 1   2 ");
+
+        let Simulation { end: crates, skipped, .. } = simulate(data, &CrateMover9001, true).unwrap();
+        assert_eq!(crates.top_crates(), "C");
+        let errors: Vec<(usize, String)> = skipped.iter().map(|e| (e.line, e.error.to_string())).collect();
+        assert_eq!(
+            errors,
+            vec![
+                (5, "no stack 3, stacks are 1 to 2".to_owned()),
+                (6, "moving from stack 2 to itself".to_owned()),
+                (7, "3 crates wanted from stack 2, only 1 there".to_owned()),
+                (8, "not a move: cannot parse integer from empty string".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_stacks_are_errors() {
+        assert_eq!("".parse::<Crates>().err(), Some(StacksError::Empty));
+        assert_eq!("[A]\n[B]\n".parse::<Crates>().err(), Some(StacksError::Header("[B]".to_owned())));
+        assert!(matches!(
+            simulate("[A]\n 1 x\n\nmove 1 from 1 to 1\n", &CrateMover9000, true),
+            Err(InputError::Stacks(StacksError::Header(_)))
+        ));
+
+        let crates: Crates = "[A]\n[B] [C]\n 1   2 ".parse().unwrap();
+        assert_eq!(crates.top_crates(), "AC");
+    }
+}
@@ -0,0 +1,97 @@
+use std::{collections::HashSet, io};
+
+use aoc::{bench, cli, input, years};
+
+const DAY: &'static str = "06";
+
+fn start_after_distinct(data: &str, size: usize) -> usize {
+    let signals: Vec<char> = data.chars().collect();
+
+    signals
+        .windows(size)
+        .enumerate()
+        .find_map(|(idx, subroutine)| {
+            if subroutine.into_iter().collect::<HashSet<_>>().len() == size {
+                Some(idx + size)
+            } else {
+                None
+            }
+        })
+        .unwrap()
+}
+
+fn part1(data: &str) -> usize {
+    start_after_distinct(data, 4)
+}
+
+fn part2(data: &str) -> usize {
+    start_after_distinct(data, 14)
+}
+
+fn main() -> io::Result<()> {
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day06.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day06.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn distinct(chars: &[char]) -> bool {
+        chars.iter().collect::<HashSet<_>>().len() == chars.len()
+    }
+
+    fn check_marker(size: usize) {
+        let name = format!(
+            "{} chars before the marker are distinct, and the first such window",
+            size
+        );
+        prop::check(
+            &name,
+            |rng| rng.string(40, "abcdefghijklmnopq"),
+            |signal| {
+                let chars: Vec<char> = signal.chars().collect();
+                if !chars.windows(size).any(distinct) {
+                    return true;
+                }
+
+                let marker = start_after_distinct(signal, size);
+                distinct(&chars[marker - size..marker])
+                    && !chars[..marker - 1].windows(size).any(distinct)
+            },
+        );
+    }
+
+    #[test]
+    fn packet_marker_is_distinct() {
+        check_marker(4);
+    }
+
+    #[test]
+    fn message_marker_is_distinct() {
+        check_marker(14);
+    }
+}
@@ -0,0 +1,408 @@
+use std::{collections::HashMap, io::Result};
+
+use aoc::{bench, cli, input, dot::Dot, repl::{self, Session}, years};
+
+const DAY: &'static str = "07";
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    size: u64,
+    dir: bool,
+    parent: Option<String>,
+    childs: Option<Vec<String>>,
+}
+
+impl Node {
+    fn root() -> Node {
+        Node {
+            name: "/".to_owned(),
+            size: 0,
+            dir: true,
+            parent: None,
+            childs: Some(vec![]),
+        }
+    }
+
+    fn new_dir(name: String, parent: String) -> Node {
+        Node {
+            name,
+            size: 0,
+            dir: true,
+            parent: Some(parent),
+            childs: Some(vec![]),
+        }
+    }
+
+    fn new_file(name: String, parent: String, size: u64) -> Node {
+        Node {
+            name,
+            size,
+            dir: false,
+            parent: Some(parent),
+            childs: None,
+        }
+    }
+
+    fn idx(&self, nodes: &HashMap<String, Node>) -> String {
+        if self.parent.is_none() {
+            return "/".to_owned();
+        }
+
+        let parent: &Node = self
+            .parent
+            .clone()
+            .map(|name| nodes.get(&name))
+            .flatten()
+            .unwrap();
+
+        let res = [parent.idx(nodes), self.name.clone()]
+            .map(|i| if i == "/" { "".to_owned() } else { i })
+            .join("/");
+        res
+    }
+
+    fn add_child(&mut self, child_name: String) {
+        if self.childs.is_some() {
+            let childs: &mut Vec<String> = self.childs.as_mut().unwrap();
+            childs.push(child_name);
+        } else {
+            unreachable!("NO CHILDS!!");
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Tree {
+    current: String,
+    root: String,
+    indexes: HashMap<String, Node>,
+}
+
+impl Tree {
+    fn new() -> Tree {
+        let root: Node = Node::root();
+        let root_idx: String = root.name.clone();
+
+        Tree {
+            indexes: HashMap::from([(root_idx.clone(), root)]),
+            current: root_idx.clone(),
+            root: root_idx.clone(),
+        }
+    }
+
+    fn add_file(&mut self, name: String, size: u64) {
+        let nodes = &mut (self.indexes);
+
+        let curr_name: String = name;
+        let mut parent_name: Option<String> = Some(self.current.clone());
+
+        let node = Node::new_file(
+            curr_name.clone(),
+            parent_name.clone().unwrap().clone(),
+            size,
+        );
+
+        let node_idx: String = node.idx(nodes);
+
+        nodes.insert(node.idx(nodes), node);
+
+        let mut parent: Option<&mut Node> = parent_name
+            .clone()
+            .map(|name| nodes.get_mut(&name.clone()))
+            .flatten();
+
+        parent
+            .iter_mut()
+            .for_each(|p| p.add_child(node_idx.clone()));
+
+        while !parent.is_none() {
+            let parent_node = parent.unwrap();
+            parent_node.size += size;
+
+            parent_name = parent_node.parent.clone();
+
+            parent = parent_name
+                .clone()
+                .map(|name| nodes.get_mut(&name.clone()))
+                .flatten();
+        }
+    }
+
+    fn add_dir(&mut self, name: String) {
+        let nodes = &mut (self.indexes);
+
+        let parent_name: String = self.current.clone();
+
+        let node: Node = Node::new_dir(name, parent_name.clone());
+        let child_idx: String = node.idx(nodes);
+
+        nodes
+            .get_mut(&parent_name.clone())
+            .iter_mut()
+            .for_each(|p| p.add_child(child_idx.clone()));
+
+        nodes.insert(node.idx(nodes), node);
+    }
+
+    fn get_node(&self, node_idx: String) -> Option<&Node> {
+        self.indexes.get(&node_idx)
+    }
+
+    fn get_root_node(&self) -> Option<&Node> {
+        self.get_node(self.root.clone())
+    }
+
+    fn to_dot(&self) -> Dot {
+        let mut idxs: Vec<&String> = self.indexes.keys().collect();
+        idxs.sort();
+
+        idxs.iter().fold(Dot::digraph("tree").attr("rankdir", "LR"), |mut dot, idx| {
+            let node = &self.indexes[*idx];
+            let label = format!("{}\n{}", node.name, node.size);
+            let shape = if node.dir { "box" } else { "plaintext" };
+            dot.add_node(idx, &[("label", &label), ("shape", shape)]);
+
+            node.childs
+                .iter()
+                .flatten()
+                .for_each(|child| dot.add_edge(idx, child, &[]));
+            dot
+        })
+    }
+
+    fn goto(&mut self, sub_dir: String) {
+        if sub_dir == self.root {
+            self.current = sub_dir;
+            return;
+        }
+
+        let current_node = self
+            .indexes
+            .get(&self.current.clone())
+            .expect("Node not found!");
+
+        self.current = if sub_dir == ".." {
+            self.indexes
+                .get(&current_node.parent.clone().expect("No parent name found!"))
+                .expect("Parent not found!")
+                .idx(&self.indexes)
+        } else {
+            let child_idx = current_node
+                .childs
+                .as_ref()
+                .expect("No childs")
+                .iter()
+                .map(|child| self.indexes.get(child).expect("No child Node"))
+                .find(|child| child.name == sub_dir)
+                .expect("No child!")
+                .idx(&self.indexes);
+
+            self.indexes
+                .get(&child_idx)
+                .expect("No child node!")
+                .idx(&self.indexes)
+        };
+    }
+}
+
+impl Tree {
+    /// Applies one line of the terminal output.
+    fn apply(&mut self, line: &str) {
+        match line {
+            cd_command if cd_command.starts_with("$ cd") => {
+                let dest: String = cd_command
+                    .split_whitespace()
+                    .skip(2)
+                    .take(1)
+                    .collect::<String>();
+                self.goto(dest);
+            }
+            ls_command if ls_command.starts_with("$ ls") => {}
+            dir_line if dir_line.starts_with("dir") => {
+                let dir_name = dir_line
+                    .split_whitespace()
+                    .skip(1)
+                    .take(1)
+                    .collect::<String>();
+                self.add_dir(dir_name);
+            }
+            file_line => {
+                let filename = file_line
+                    .split_whitespace()
+                    .skip(1)
+                    .take(1)
+                    .collect::<String>();
+                let size = file_line
+                    .split_whitespace()
+                    .take(1)
+                    .collect::<String>()
+                    .parse::<u64>()
+                    .expect("Invalid size!");
+                self.add_file(filename, size);
+            }
+        }
+    }
+
+    /// Indented listing of `idx` and everything below it.
+    fn listing(&self, idx: &str, depth: usize, res: &mut Vec<String>) {
+        let Some(node) = self.indexes.get(idx) else {
+            return;
+        };
+        let marker = if idx == self.current { "  <- current" } else { "" };
+        let kind = if node.dir { "dir" } else { "file" };
+        res.push(format!("{}- {} ({}, size={}){}", "  ".repeat(depth), node.name, kind, node.size, marker));
+
+        let mut childs: Vec<&String> = node.childs.iter().flatten().collect();
+        childs.sort();
+        childs.iter().for_each(|child| self.listing(child, depth + 1, res));
+    }
+}
+
+fn parse_tree(data: &str) -> Tree {
+    let mut tree = Tree::new();
+    data.lines().for_each(|line| tree.apply(line));
+    tree
+}
+
+/// Replays the terminal output one line at a time.
+struct TreeSession {
+    lines: Vec<String>,
+    applied: usize,
+    tree: Tree,
+}
+
+impl TreeSession {
+    fn new(data: &str) -> Self {
+        Self {
+            lines: data.lines().map(|line| line.to_owned()).collect(),
+            applied: 0,
+            tree: Tree::new(),
+        }
+    }
+
+    fn node(&self, path: &str) -> std::result::Result<&Node, String> {
+        self.tree
+            .get_node(path.trim_end_matches('/').to_owned())
+            .or_else(|| self.tree.get_root_node().filter(|_| path == "/"))
+            .ok_or_else(|| format!("No such file or directory: {}", path))
+    }
+}
+
+impl Session for TreeSession {
+    fn step(&mut self, n: usize) -> std::result::Result<String, String> {
+        if self.applied == self.lines.len() {
+            return Err(format!("All {} lines applied", self.lines.len()));
+        }
+        let lines = &self.lines[self.applied..(self.applied + n).min(self.lines.len())];
+        lines.iter().for_each(|line| self.tree.apply(line));
+        self.applied += lines.len();
+
+        let mut res = repl::last_steps(lines.to_vec());
+        res.push(format!("[{}/{} lines, in {}]", self.applied, self.lines.len(), self.tree.current));
+        Ok(res.join("\n"))
+    }
+
+    fn show(&self) -> String {
+        let mut res = vec![];
+        self.tree.listing(&self.tree.root, 0, &mut res);
+        res.join("\n")
+    }
+
+    fn query(&self, args: &[&str]) -> std::result::Result<String, String> {
+        match args {
+            ["pwd"] => Ok(self.tree.current.clone()),
+            ["size", path] => self.node(path).map(|node| node.size.to_string()),
+            ["ls", path] => self.node(path).map(|node| {
+                let mut childs: Vec<String> = node
+                    .childs
+                    .iter()
+                    .flatten()
+                    .filter_map(|child| self.tree.get_node(child.clone()))
+                    .map(|child| {
+                        if child.dir {
+                            format!("dir {}", child.name)
+                        } else {
+                            format!("{} {}", child.size, child.name)
+                        }
+                    })
+                    .collect();
+                childs.sort();
+                childs.join("\n")
+            }),
+            ["small", max] => {
+                let max = max.parse::<u64>().map_err(|_| format!("Invalid size: {}", max))?;
+                let dirs: Vec<&Node> = self.tree.indexes.values().filter(|node| node.dir && node.size <= max).collect();
+                Ok(format!("{} directories, total {}", dirs.len(), dirs.iter().map(|node| node.size).sum::<u64>()))
+            }
+            _ => Err(format!("Unknown query: {}", args.join(" "))),
+        }
+    }
+
+    fn query_help(&self) -> Vec<String> {
+        vec![
+            "pwd            current directory".to_owned(),
+            "size PATH      total size, e.g. `size /a/e`".to_owned(),
+            "ls PATH        content of a directory".to_owned(),
+            "small MAX      directories of at most MAX, as in part 1".to_owned(),
+        ]
+    }
+}
+
+fn part1(data: &str) -> u64 {
+    let tree = parse_tree(data);
+    tree.indexes
+        .values()
+        .filter(|node| node.dir && node.size <= 100000)
+        .map(|node| node.size)
+        .sum()
+}
+
+fn part2(data: &str) -> u64 {
+    let tree = parse_tree(data);
+    let remain_space: u64 = 70000000 - tree.get_root_node().unwrap().size;
+    let space_needed: u64 = 30000000 - remain_space;
+
+    let mut dirs: Vec<&Node> = tree.indexes.values().filter(|node| node.dir && node.size >= space_needed).collect();
+    dirs.sort_by(|a, b| a.size.cmp(&b.size));
+    dirs.first().unwrap().size
+}
+
+fn main() -> Result<()> {
+    if cli::flag("--repl") {
+        let data = match cli::value("--repl").as_deref() {
+            Some("test") => input!("day07.test").to_owned(),
+            _ => cli::input()?.unwrap_or_else(|| input!("day07.in").to_owned()),
+        };
+        return repl::run("day07", || Ok(TreeSession::new(&data)));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day07.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day07.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--dot") {
+        parse_tree(&input_data).to_dot().save(path)?;
+    }
+
+    Ok(())
+}
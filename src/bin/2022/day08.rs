@@ -1,8 +1,26 @@
 use std::{collections::HashSet, io::Result};
 
-const YEAR: &'static str = "2022";
+use aoc::{
+    bench,
+    cli,
+    input,
+    image::{Image, Rgb},
+    render::{Color, Table},
+    years,
+};
+
 const DAY: &'static str = "08";
 
+fn parse_digits(data: &str) -> Vec<Vec<i8>> {
+    data.lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).unwrap() as i8)
+                .collect()
+        })
+        .collect()
+}
+
 fn ranges(rows_len: usize, columns_len: usize, reverse: bool) -> (Vec<usize>, Vec<usize>) {
     if reverse {
         (
@@ -18,14 +36,7 @@ fn ranges(rows_len: usize, columns_len: usize, reverse: bool) -> (Vec<usize>, Ve
 }
 
 fn part1(data: &str) -> usize {
-    let digits: Vec<Vec<i8>> = data
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as i8)
-                .collect()
-        })
-        .collect();
+    let digits: Vec<Vec<i8>> = parse_digits(data);
 
     let rows_len: usize = digits.len();
     let columns_len: usize = digits[1].len();
@@ -87,23 +98,24 @@ fn part1(data: &str) -> usize {
         .flatten()
         .collect::<HashSet<(usize, usize)>>();
 
+    if cli::verbose() {
+        let table = Table::grid(&format!("Trees {}x{}", columns_len, rows_len), &digits)
+            .spacing(0)
+            .highlight_all(global_set.iter().copied(), Color::Green);
+        eprintln!("{}", table);
+    }
+
     global_set.len()
 }
 
 fn part2(data: &str) -> isize {
-    let digits: Vec<Vec<i8>> = data
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as i8)
-                .collect()
-        })
-        .collect();
+    let digits: Vec<Vec<i8>> = parse_digits(data);
 
     let rows_len: usize = digits.len();
     let columns_len: usize = digits[1].len();
 
     let mut max: isize = 0;
+    let mut best: (usize, usize) = (0, 0);
 
     (0..rows_len).for_each(|r| {
         (0..columns_len).for_each(|c| {
@@ -170,29 +182,125 @@ fn part2(data: &str) -> isize {
             let score = up_score * right_score * down_score * left_score;
             if max < score {
                 max = score;
+                best = (r, c);
             }
         });
     });
 
+    if cli::verbose() {
+        let table = Table::grid(&format!("Trees {}x{}", columns_len, rows_len), &digits)
+            .spacing(0)
+            .highlight(best.0, best.1, Color::Yellow);
+        eprintln!("{}", table);
+    }
+
     max
 }
 
+fn image(data: &str) -> Image {
+    let digits: Vec<Vec<i8>> = parse_digits(data);
+
+    Image::from_fn(digits[0].len(), digits.len(), |x, y| {
+        Rgb::BLACK.blend(Rgb::GREEN, digits[y][x] as usize + 1, 10)
+    })
+    .scale(4)
+}
+
 fn main() -> Result<()> {
     println!();
     println!(
         "---( AOC{} - Day {} )-----------------------[Rust]----",
-        YEAR, DAY
+        years::DEFAULT_YEAR, DAY
     );
 
-    let test_data = include_str!("day08.test");
-    println!("Test :: Part 1 ====>     {}", part1(&test_data));
-    println!("Test :: Part 2 ====>     {}", part2(&test_data));
-    println!("--------------------------------------------------------");
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day08.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
 
-    let input_data = include_str!("day08.in");
-    println!("Input:: Part 1 ====>     {}", part1(&input_data));
-    println!("Input:: Part 2 ====>     {}", part2(&input_data));
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day08.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
     println!("--------------------------------------------------------");
 
+    if let Some(path) = cli::value("--image") {
+        image(&input_data).save(path)?;
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn forest(rng: &mut prop::Rng) -> Vec<Vec<u8>> {
+        let (rows, columns) = (2 + rng.below(5), 2 + rng.below(5));
+        (0..rows)
+            .map(|_| (0..columns).map(|_| rng.below(10) as u8).collect())
+            .collect()
+    }
+
+    fn is_rectangle(forest: &[Vec<u8>]) -> bool {
+        forest.len() >= 2
+            && forest[0].len() >= 2
+            && forest
+                .iter()
+                .all(|row| row.len() == forest[0].len() && row.iter().all(|t| *t < 10))
+    }
+
+    fn format_forest(forest: &[Vec<u8>]) -> String {
+        forest
+            .iter()
+            .map(|row| row.iter().map(|t| t.to_string()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    fn visible(forest: &[Vec<u8>], r: usize, c: usize) -> bool {
+        let height = forest[r][c];
+        let row = &forest[r];
+        let column: Vec<u8> = forest.iter().map(|row| row[c]).collect();
+
+        [&row[..c], &row[c + 1..], &column[..r], &column[r + 1..]]
+            .iter()
+            .any(|line| line.iter().all(|t| *t < height))
+    }
+
+    #[test]
+    fn parse_format_round_trip() {
+        prop::check("digits parse back to the forest", forest, |forest| {
+            !is_rectangle(forest)
+                || parse_digits(&format_forest(forest))
+                    == forest
+                        .iter()
+                        .map(|row| row.iter().map(|t| *t as i8).collect())
+                        .collect::<Vec<Vec<i8>>>()
+        });
+    }
+
+    #[test]
+    fn visible_trees_match_line_of_sight() {
+        prop::check(
+            "part 1 counts trees taller than one full line of sight",
+            forest,
+            |forest| {
+                if !is_rectangle(forest) {
+                    return true;
+                }
+                let expected = (0..forest.len())
+                    .flat_map(|r| (0..forest[0].len()).map(move |c| (r, c)))
+                    .filter(|&(r, c)| visible(forest, r, c))
+                    .count();
+                part1(&format_forest(forest)) == expected
+            },
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Display},
+    io::{self, Result},
+};
+
+use aoc::{
+    animation::{Animation, Player},
+    bench,
+    cli,
+    input,
+    image::{Image, Rgb},
+    render::{Color, Table},
+    repl::{self, Session},
+    years,
+};
+
+const DAY: &'static str = "09";
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Position {
+    y: isize,
+    x: isize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Debug for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Position {
+    fn init() -> Position {
+        Position { y: 0, x: 0 }
+    }
+
+    fn next_position(&self, direction: &str) -> Position {
+        match direction {
+            "U" => Position {
+                y: self.y + 1,
+                x: self.x,
+            },
+            "D" => Position {
+                y: self.y - 1,
+                x: self.x,
+            },
+            "R" => Position {
+                y: self.y,
+                x: self.x + 1,
+            },
+            "L" => Position {
+                y: self.y,
+                x: self.x - 1,
+            },
+            _ => unreachable!("UNKNOWN DIRECTION!!"),
+        }
+    }
+
+    fn distance(self, other: Position) -> Position {
+        Position {
+            y: self.y - other.y,
+            x: self.x - other.x,
+        }
+    }
+}
+
+struct Rope {
+    size: usize,
+    knots: HashMap<usize, Position>,
+    tail_positions: HashSet<Position>,
+}
+
+impl Rope {
+    fn apply(&mut self, direction: &str, steps: isize) {
+        (0..steps).for_each(|_step| self.step(direction));
+    }
+
+    fn step(&mut self, direction: &str) {
+        let head = self
+            .knots
+            .get(&0)
+            .expect("NO HEAD!")
+            .clone()
+            .next_position(direction);
+        self.knots.insert(0, head);
+
+        (0..self.size)
+            .into_iter()
+            .collect::<Vec<usize>>()
+            .windows(2)
+            .for_each(|window| {
+                let head = self.knots.get(&window[0]).expect("NO HEAD!").clone();
+
+                let t = window[1];
+                let mut tail = self.knots.get(&t).expect("NO TAIL!!").clone();
+
+                let distance = head.distance(tail);
+
+                if distance.x.abs() > 1 && distance.y.abs() > 1 {
+                    tail = Position {
+                        x: head.x - (distance.x.signum()),
+                        y: head.y - (distance.y.signum()),
+                    };
+                } else {
+                    if distance.x.abs() > 1 {
+                        tail = Position {
+                            x: head.x - (distance.x.signum()),
+                            y: head.y,
+                        };
+                    }
+                    if distance.y.abs() > 1 {
+                        tail = Position {
+                            x: head.x,
+                            y: head.y - (distance.y.signum()),
+                        };
+                    }
+                }
+
+                self.knots.insert(t, tail);
+                if t == self.size - 1 {
+                    self.tail_positions.insert(tail.clone());
+                }
+            });
+    }
+}
+
+impl Rope {
+    fn init(size: usize) -> Self {
+        Self {
+            size,
+            knots: (0..size).map(|idx| (idx, Position::init())).collect(),
+            tail_positions: HashSet::from([Position::init()]),
+        }
+    }
+}
+
+struct RopeAnimation {
+    rope: Rope,
+    moves: Vec<(String, isize)>,
+    move_idx: usize,
+    done_steps: isize,
+}
+
+impl RopeAnimation {
+    // Cells shown around the head.
+    const VIEW_WIDTH: isize = 61;
+    const VIEW_HEIGHT: isize = 25;
+
+    fn new(data: &str, size: usize) -> std::result::Result<Self, String> {
+        if size == 0 {
+            return Err("A rope needs at least one knot".to_owned());
+        }
+        let moves = data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| match line.split(" ").collect::<Vec<&str>>()[..] {
+                [direction @ ("U" | "D" | "L" | "R"), steps] => steps
+                    .parse::<isize>()
+                    .map(|steps| (direction.to_owned(), steps))
+                    .map_err(|_| format!("line {}: invalid step count in {:?}", idx + 1, line)),
+                _ => Err(format!("line {}: expected a direction and a step count, got {:?}", idx + 1, line)),
+            })
+            .collect::<std::result::Result<Vec<(String, isize)>, String>>()?;
+
+        Ok(Self {
+            rope: Rope::init(size),
+            moves,
+            move_idx: 0,
+            done_steps: 0,
+        })
+    }
+}
+
+impl Animation for RopeAnimation {
+    fn title(&self) -> String {
+        let current = match self.moves.get(self.move_idx) {
+            Some((direction, steps)) => format!("{} {} ({}/{})", direction, steps, self.done_steps, steps),
+            None => "done".to_owned(),
+        };
+        format!(
+            "AOC{} - Day {} :: move {}/{} {} :: {} tail positions",
+            years::DEFAULT_YEAR,
+            DAY,
+            self.move_idx.min(self.moves.len()),
+            self.moves.len(),
+            current,
+            self.rope.tail_positions.len()
+        )
+    }
+
+    fn frame(&self) -> String {
+        let head = self.rope.knots[&0];
+        let left = head.x - Self::VIEW_WIDTH / 2;
+        let top = head.y + Self::VIEW_HEIGHT / 2;
+
+        let mut grid: Vec<Vec<char>> = (0..Self::VIEW_HEIGHT)
+            .map(|r| {
+                (0..Self::VIEW_WIDTH)
+                    .map(|c| {
+                        let position = Position { x: left + c, y: top - r };
+                        if position == Position::init() {
+                            's'
+                        } else if self.rope.tail_positions.contains(&position) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Knots closer to the head are drawn last, on top of the others.
+        (0..self.rope.size).rev().for_each(|idx| {
+            let knot = self.rope.knots[&idx];
+            let (r, c) = (top - knot.y, knot.x - left);
+            if (0..Self::VIEW_HEIGHT).contains(&r) && (0..Self::VIEW_WIDTH).contains(&c) {
+                grid[r as usize][c as usize] = match idx {
+                    0 => 'H',
+                    i => char::from_digit(i as u32, 36).unwrap(),
+                };
+            }
+        });
+
+        Table::grid(&format!("Head at {}", head), &grid)
+            .spacing(0)
+            .highlight((top - head.y) as usize, (head.x - left) as usize, Color::Red)
+            .render()
+    }
+
+    fn advance(&mut self) -> bool {
+        while self.moves.get(self.move_idx).map(|(_, steps)| *steps) == Some(0) {
+            self.move_idx += 1;
+        }
+        let Some((direction, steps)) = self.moves.get(self.move_idx) else {
+            return false;
+        };
+
+        self.rope.step(direction);
+        self.done_steps += 1;
+        if self.done_steps == *steps {
+            self.move_idx += 1;
+            self.done_steps = 0;
+        }
+        true
+    }
+}
+
+/// Whole moves at a time, on top of the animation model.
+struct RopeSession(RopeAnimation);
+
+impl Session for RopeSession {
+    fn step(&mut self, n: usize) -> std::result::Result<String, String> {
+        let animation = &mut self.0;
+        if animation.move_idx >= animation.moves.len() {
+            return Err(format!("All {} moves applied", animation.moves.len()));
+        }
+
+        let mut applied = vec![];
+        for _ in 0..n {
+            let Some((direction, steps)) = animation.moves.get(animation.move_idx) else {
+                break;
+            };
+            applied.push(format!("{:>4}: {} {}", animation.move_idx + 1, direction, steps));
+            animation.rope.apply(direction, steps - animation.done_steps);
+            animation.move_idx += 1;
+            animation.done_steps = 0;
+        }
+        let mut res = repl::last_steps(applied);
+        res.push(format!("{} tail positions", animation.rope.tail_positions.len()));
+        Ok(res.join("\n"))
+    }
+
+    fn show(&self) -> String {
+        format!("{}\n{}", self.0.title(), self.0.frame())
+    }
+
+    fn query(&self, args: &[&str]) -> std::result::Result<String, String> {
+        let animation = &self.0;
+        match args {
+            ["knot", idx] => idx
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| animation.rope.knots.get(&idx))
+                .map(|position| position.to_string())
+                .ok_or_else(|| format!("No knot {} (0 to {})", idx, animation.rope.size - 1)),
+            ["knots"] => Ok((0..animation.rope.size)
+                .map(|idx| format!("{:>2}: {}", idx, animation.rope.knots[&idx]))
+                .collect::<Vec<String>>()
+                .join("\n")),
+            ["tail"] => Ok(animation.rope.tail_positions.len().to_string()),
+            ["next"] => animation
+                .moves
+                .get(animation.move_idx)
+                .map(|(direction, steps)| format!("{} {}", direction, steps))
+                .ok_or_else(|| "No move left".to_owned()),
+            _ => Err(format!("Unknown query: {}", args.join(" "))),
+        }
+    }
+
+    fn query_help(&self) -> Vec<String> {
+        vec![
+            "knot N         position of knot N, 0 being the head".to_owned(),
+            "knots          positions of all the knots".to_owned(),
+            "tail           positions visited by the tail".to_owned(),
+            "next           next move".to_owned(),
+        ]
+    }
+}
+
+fn part1(data: &str) -> usize {
+    let mut rope = Rope::init(2);
+
+    data.lines()
+        .map(|line| {
+            let line_parts = line.split(" ").collect::<Vec<&str>>();
+            (line_parts[0], line_parts[1].parse::<isize>().unwrap())
+        })
+        .for_each(|(direction, steps)| {
+            rope.apply(direction, steps);
+        });
+
+    rope.tail_positions.len()
+}
+
+fn part2(data: &str) -> usize {
+    let mut rope = Rope::init(10);
+
+    data.lines()
+        .map(|line| {
+            let line_parts = line.split(" ").collect::<Vec<&str>>();
+            (line_parts[0], line_parts[1].parse::<isize>().unwrap())
+        })
+        .for_each(|(direction, steps)| {
+            rope.apply(direction, steps);
+        });
+
+    rope.tail_positions.len()
+}
+
+fn image(data: &str) -> Image {
+    let mut short_rope = Rope::init(2);
+    let mut long_rope = Rope::init(10);
+
+    data.lines()
+        .map(|line| {
+            let line_parts = line.split(" ").collect::<Vec<&str>>();
+            (line_parts[0], line_parts[1].parse::<isize>().unwrap())
+        })
+        .for_each(|(direction, steps)| {
+            short_rope.apply(direction, steps);
+            long_rope.apply(direction, steps);
+        });
+
+    let trails = short_rope.tail_positions.union(&long_rope.tail_positions);
+    let (min_x, max_x, min_y, max_y) = trails.fold(
+        (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
+        |(min_x, max_x, min_y, max_y), p| (min_x.min(p.x), max_x.max(p.x), min_y.min(p.y), max_y.max(p.y)),
+    );
+
+    // y grows upwards in the puzzle and downwards in the image.
+    Image::from_fn((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize, |x, y| {
+        let position = Position {
+            x: min_x + x as isize,
+            y: max_y - y as isize,
+        };
+        match (
+            short_rope.tail_positions.contains(&position),
+            long_rope.tail_positions.contains(&position),
+        ) {
+            _ if position == Position::init() => Rgb::RED,
+            (true, true) => Rgb::YELLOW,
+            (true, false) => Rgb::BLUE,
+            (false, true) => Rgb::GREEN,
+            (false, false) => Rgb::BLACK,
+        }
+    })
+    .scale(2)
+}
+
+fn main() -> Result<()> {
+    if cli::flag("--animate") {
+        let data = match cli::value("--animate").as_deref() {
+            Some("test") => input!("day09.test2"),
+            _ => input!("day09.in"),
+        };
+        let mut animation =
+            RopeAnimation::new(data, 10).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        return Player::from_cli().play(&mut animation);
+    }
+    if cli::flag("--repl") {
+        let data = match cli::value("--repl").as_deref() {
+            Some("test") => input!("day09.test2").to_owned(),
+            _ => cli::input()?.unwrap_or_else(|| input!("day09.in").to_owned()),
+        };
+        let knots = cli::value("--knots").and_then(|knots| knots.parse().ok()).unwrap_or(10);
+        return repl::run("day09", || RopeAnimation::new(&data, knots).map(RopeSession));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day09.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+
+        let test_data2 = input!("day09.test2");
+        println!("Test2:: Part 1 ====>     {}", part1(&test_data2));
+        println!("Test2:: Part 2 ====>     {}", part2(&test_data2));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day09.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--image") {
+        image(&input_data).save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    const DIRECTIONS: [&str; 4] = ["U", "D", "L", "R"];
+
+    fn moves(rng: &mut prop::Rng) -> Vec<(usize, usize)> {
+        rng.vec(30, |rng| (rng.below(4) as usize, 1 + rng.below(6) as usize))
+    }
+
+    fn touching(a: Position, b: Position) -> bool {
+        let distance = a.distance(b);
+        distance.x.abs() <= 1 && distance.y.abs() <= 1
+    }
+
+    #[test]
+    fn animation_skips_empty_moves() {
+        let mut animation = RopeAnimation::new("R 0\nU 2\nL 0\n", 2).unwrap();
+        assert!(animation.advance() && animation.advance());
+        assert!(!animation.advance());
+        assert_eq!(animation.move_idx, 3);
+    }
+
+    #[test]
+    fn animation_rejects_garbage() {
+        let error = |data: &str, size: usize| RopeAnimation::new(data, size).err();
+        assert_eq!(
+            error("R 1\nX 2\n", 2).as_deref(),
+            Some("line 2: expected a direction and a step count, got \"X 2\"")
+        );
+        assert_eq!(error("R 1\nU two\n", 2).as_deref(), Some("line 2: invalid step count in \"U two\""));
+        assert_eq!(
+            error("garbage", 2).as_deref(),
+            Some("line 1: expected a direction and a step count, got \"garbage\"")
+        );
+        assert_eq!(error("R 1\n", 0).as_deref(), Some("A rope needs at least one knot"));
+    }
+
+    #[test]
+    fn knots_stay_touching() {
+        prop::check(
+            "every knot touches the previous one after each step",
+            |rng| (2 + rng.below(9) as usize, moves(rng)),
+            |(size, moves)| {
+                if *size < 2 {
+                    return true;
+                }
+                let mut rope = Rope::init(*size);
+                moves.iter().all(|(direction, steps)| {
+                    (0..*steps).all(|_| {
+                        rope.step(DIRECTIONS[direction % 4]);
+                        (1..*size).all(|idx| touching(rope.knots[&(idx - 1)], rope.knots[&idx]))
+                    })
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn tail_visits_are_bounded_by_moves() {
+        prop::check(
+            "the tail visits at most one new position per step",
+            |rng| (2 + rng.below(9) as usize, moves(rng)),
+            |(size, moves)| {
+                if *size < 2 {
+                    return true;
+                }
+                let mut rope = Rope::init(*size);
+                moves.iter().for_each(|(direction, steps)| {
+                    rope.apply(DIRECTIONS[direction % 4], *steps as isize)
+                });
+                rope.tail_positions.len() <= 1 + moves.iter().map(|(_, steps)| steps).sum::<usize>()
+            },
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::io::Result;
+
+use aoc::{
+    animation::{Animation, Player},
+    bench,
+    cli,
+    input,
+    image::{Image, Rgb},
+    render::{Color, Table},
+    years,
+};
+
+const DAY: &'static str = "09";
+
+#[derive(Debug)]
+struct Cpu {
+    x_register: isize,
+    cycles: Vec<isize>,
+}
+
+impl Cpu {
+    fn init() -> Cpu {
+        Cpu {
+            x_register: 1,
+            cycles: vec![1],
+        }
+    }
+
+    fn apply(&mut self, instruction: &str) {
+        match instruction {
+            "noop" => {
+                self.cycles.push(self.x_register);
+            }
+            add if add.starts_with("addx") => {
+                let num = add
+                    .split(" ")
+                    .collect::<Vec<&str>>()
+                    .get(1)
+                    .unwrap()
+                    .parse::<isize>()
+                    .unwrap();
+
+                self.cycles.push(self.x_register);
+                self.x_register += num;
+                self.cycles.push(self.x_register);
+            }
+            _ => unreachable!("UNKNOWN INSTRUICTION!!"),
+        }
+    }
+
+    fn signal_strenghts(&self, cycle: usize) -> isize {
+        cycle as isize * self.cycles[cycle - 1]
+    }
+
+    fn pixel_state(&self, r: isize, c: isize) -> char {
+        let cycle_value: isize = *self.cycles.get((r * 40 + c) as usize).unwrap() - 1;
+
+        if c >= cycle_value && c <= cycle_value + 2  {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+struct CrtAnimation {
+    cpu: Cpu,
+    cycle: usize,
+}
+
+impl CrtAnimation {
+    const PIXELS: usize = 240;
+
+    fn new(data: &str) -> Self {
+        let mut cpu = Cpu::init();
+        data.lines().for_each(|line| cpu.apply(line));
+
+        Self { cpu, cycle: 0 }
+    }
+}
+
+impl Animation for CrtAnimation {
+    fn title(&self) -> String {
+        format!(
+            "AOC{} - Day {} :: cycle {} :: X = {}",
+            years::DEFAULT_YEAR,
+            DAY,
+            self.cycle + 1,
+            self.cpu.cycles[self.cycle.min(Self::PIXELS - 1)]
+        )
+    }
+
+    fn frame(&self) -> String {
+        let x_register = self.cpu.cycles[self.cycle.min(Self::PIXELS - 1)];
+        let sprite: Vec<char> = (0..40)
+            .map(|c| if (x_register - 1..=x_register + 1).contains(&c) { '#' } else { '.' })
+            .collect();
+
+        let screen: Vec<Vec<char>> = (0..6)
+            .map(|r| {
+                (0..40)
+                    .map(|c| match r * 40 + c {
+                        idx if idx < self.cycle => self.cpu.pixel_state(r as isize, c as isize),
+                        _ => ' ',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut table = Table::grid("CRT", &screen).spacing(0);
+        if self.cycle < Self::PIXELS {
+            table = table.highlight(self.cycle / 40, self.cycle % 40, Color::Red);
+        }
+        format!("{}{}", Table::grid("Sprite", &[sprite]).spacing(0), table)
+    }
+
+    fn advance(&mut self) -> bool {
+        if self.cycle >= Self::PIXELS {
+            return false;
+        }
+        self.cycle += 1;
+        true
+    }
+}
+
+fn part1(data: &str) -> isize {
+    let mut cpu = Cpu::init();
+    data.lines().for_each(|line| cpu.apply(line));
+
+    [20, 60, 100, 140, 180, 220]
+        .iter()
+        .map(|cycle| cpu.signal_strenghts(*cycle))
+        .sum()
+}
+
+fn part2(data: &str) {
+    let mut cpu = Cpu::init();
+    data.lines().for_each(|line| cpu.apply(line));
+
+    println!();
+    (0..6).for_each(|r| {
+        (0..40).for_each(|c| {
+            print!("{}", cpu.pixel_state(r, c));
+        });
+        println!();
+    });
+    println!();
+}
+
+fn image(data: &str) -> Image {
+    let mut cpu = Cpu::init();
+    data.lines().for_each(|line| cpu.apply(line));
+
+    Image::from_fn(40, 6, |x, y| match cpu.pixel_state(y as isize, x as isize) {
+        '#' => Rgb::YELLOW,
+        _ => Rgb::BLACK,
+    })
+    .scale(10)
+}
+
+fn main() -> Result<()> {
+    if cli::flag("--animate") {
+        let data = match cli::value("--animate").as_deref() {
+            Some("test") => input!("day10.test"),
+            _ => input!("day10.in"),
+        };
+        return Player::from_cli().play(&mut CrtAnimation::new(data));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day10.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     ");
+        part2(&test_data);
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day10.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     ");
+        bench::time(2, || part2(&input_data));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--image") {
+        image(&input_data).save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn program(rng: &mut prop::Rng) -> Vec<i64> {
+        // `0` stands for `noop`.
+        rng.vec(40, |rng| if rng.bool() { 0 } else { rng.range(-20..=20) })
+    }
+
+    fn instruction(arg: i64) -> String {
+        if arg == 0 {
+            "noop".to_owned()
+        } else {
+            format!("addx {}", arg)
+        }
+    }
+
+    #[test]
+    fn cycles_follow_instructions() {
+        prop::check(
+            "noop takes one cycle, addx two and then updates X",
+            program,
+            |program| {
+                let mut cpu = Cpu::init();
+                program.iter().all(|arg| {
+                    let before = (cpu.cycles.len(), cpu.x_register);
+                    cpu.apply(&instruction(*arg));
+                    let cycles = if *arg == 0 { 1 } else { 2 };
+                    cpu.cycles.len() == before.0 + cycles
+                        && cpu.x_register == before.1 + *arg as isize
+                })
+            },
+        );
+    }
+}
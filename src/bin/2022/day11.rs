@@ -0,0 +1,495 @@
+use std::{collections::HashMap, io, num::ParseIntError, str::FromStr};
+
+use aoc::{bench, cli, input, dot::Dot, repl::{self, Session}, years};
+
+type Throws = HashMap<(usize, usize), usize>;
+
+const DAY: &'static str = "11";
+
+#[derive(Debug)]
+enum OperationMemberType {
+    Number(usize),
+    Old,
+}
+use OperationMemberType::*;
+
+impl FromStr for OperationMemberType {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "old" => Old,
+            num => Number(num.parse()?),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum OperationType {
+    Add,
+    Multiply,
+    Divisible,
+}
+use OperationType::*;
+
+impl FromStr for OperationType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "+" => Add,
+            "*" => Multiply,
+            _ => return Err(format!("unknown operator {:?}", s)),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Operation {
+    op: OperationType,
+    left: OperationMemberType,
+    right: OperationMemberType,
+}
+
+impl Operation {
+    fn divisible(n: usize) -> Self {
+        Self {
+            op: Divisible,
+            left: Old,
+            right: Number(n),
+        }
+    }
+
+    fn exec(&self, item: usize) -> usize {
+        let right = match self.right {
+            Old => item,
+            Number(n) => n,
+        };
+        let left = match self.left {
+            Old => item,
+            Number(n) => n,
+        };
+
+        match self.op {
+            Add => left + right,
+            Multiply => left * right,
+            _ => unreachable!("Not executable op : {:?}!", self.op),
+        }
+    }
+
+    fn check(&self, item: usize) -> bool {
+        let right = match self.right {
+            Old => item,
+            Number(n) => n,
+        };
+        let left = match self.left {
+            Old => item,
+            Number(n) => n,
+        };
+        match self.op {
+            Divisible => left % right == 0,
+            _ => unreachable!("Not checkable op : {:?}!", self.op),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [left, op, right] = s.split(" ").collect::<Vec<&str>>()[..] else {
+            return Err(format!("invalid operation {:?}", s));
+        };
+        let member = |member: &str| {
+            member
+                .parse::<OperationMemberType>()
+                .map_err(|_| format!("invalid operand {:?} in {:?}", member, s))
+        };
+        Ok(Operation {
+            op: op.parse()?,
+            left: member(left)?,
+            right: member(right)?,
+        })
+    }
+}
+
+struct Monkey {
+    _id: usize,
+    items: Vec<usize>,
+    operation: Operation,
+    test: Operation,
+    true_dest: usize,
+    false_dest: usize,
+    items_inspected_count: usize,
+}
+impl Monkey {
+    fn play(&mut self, divider: usize) -> HashMap<usize, Vec<usize>> {
+        let mut res = HashMap::<usize, Vec<usize>>::new();
+        self.items_inspected_count += self.items.len();
+        self.items.iter().for_each(|item| {
+            let op_res = self.operation.exec(*item);
+            let op_res: usize = op_res / divider;
+
+            let check_res = self.test.check(op_res);
+            let dest_key = if check_res {
+                self.true_dest
+            } else {
+                self.false_dest
+            };
+            res.entry(dest_key).or_insert(vec![]).push(op_res);
+        });
+        res
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<&str>>();
+        let line = |idx: usize| {
+            lines
+                .get(idx)
+                .copied()
+                .ok_or_else(|| format!("missing line {} of {:?}", idx + 1, lines.first().unwrap_or(&"")))
+        };
+        // The number of a line, whatever the words around it.
+        let number = |idx: usize| {
+            let line = line(idx)?;
+            line.chars()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse::<usize>()
+                .map_err(|_| format!("expected a number in {:?}", line))
+        };
+
+        let id = number(0)?;
+        let items = line(1)?
+            .split(":")
+            .map(|part| part.trim())
+            .skip(1)
+            .take(1)
+            .flat_map(|part| part.split(","))
+            .map(|str| str.trim().parse::<usize>().map_err(|_| format!("invalid item {:?}", str.trim())))
+            .collect::<Result<Vec<usize>, String>>()?;
+        let operation = line(2)?
+            .split("=")
+            .map(|part| part.trim())
+            .nth(1)
+            .ok_or_else(|| format!("expected an operation in {:?}", lines[2]))?
+            .parse()?;
+        let test = match number(3)? {
+            0 => return Err(format!("divisible by 0 in {:?}", lines[3])),
+            n => Operation::divisible(n),
+        };
+
+        let true_dest = number(4)?;
+        let false_dest = number(5)?;
+
+        Ok(Monkey {
+            _id: id,
+            items,
+            operation,
+            test,
+            true_dest,
+            false_dest,
+            items_inspected_count: 0,
+        })
+    }
+}
+
+fn parse_monkeys(data: &str) -> Vec<Monkey> {
+    data.split("\n\n")
+        .map(|monkey_str| monkey_str.parse::<Monkey>().unwrap())
+        .collect::<Vec<Monkey>>()
+}
+
+/// Plays the rounds and counts the items thrown from one monkey to another.
+fn play_rounds(monkeys: &mut [Monkey], rounds: usize) -> Throws {
+    let mut throws = Throws::new();
+
+    (0..rounds).for_each(|_| {
+        (0..monkeys.len()).for_each(|i| {
+            let monkey = monkeys.get_mut(i).unwrap();
+            let res = monkey.play(3);
+            monkey.items.clear();
+            res.iter().for_each(|(key, value)| {
+                *throws.entry((i, *key)).or_insert(0) += value.len();
+                let monkey_dest = monkeys.get_mut(*key).unwrap();
+                value.iter().for_each(|item| {
+                    monkey_dest.items.push(*item);
+                });
+            });
+        });
+    });
+
+    throws
+}
+
+fn throw_graph(data: &str) -> Dot {
+    let mut monkeys = parse_monkeys(data);
+    let throws = play_rounds(&mut monkeys, 20);
+
+    monkeys.iter().enumerate().fold(Dot::digraph("monkeys"), |mut dot, (i, monkey)| {
+        let label = format!("Monkey {}\ninspected {}", i, monkey.items_inspected_count);
+        dot.add_node(&i.to_string(), &[("label", &label), ("shape", "box")]);
+
+        [(monkey.true_dest, "true", "darkgreen"), (monkey.false_dest, "false", "red")]
+            .iter()
+            .for_each(|(dest, test, color)| {
+                let count = throws.get(&(i, *dest)).unwrap_or(&0);
+                let label = format!("{}: {}", test, count);
+                dot.add_edge(&i.to_string(), &dest.to_string(), &[("label", &label), ("color", color)]);
+            });
+        dot
+    })
+}
+
+/// Plays rounds on demand, keeping the throws of all of them.
+struct MonkeySession {
+    monkeys: Vec<Monkey>,
+    rounds: usize,
+    throws: Throws,
+}
+
+impl MonkeySession {
+    /// Parses the monkeys, checking they only throw to one another.
+    fn new(data: &str) -> Result<Self, String> {
+        let monkeys = data
+            .split("\n\n")
+            .enumerate()
+            .map(|(idx, monkey_str)| monkey_str.parse::<Monkey>().map_err(|e| format!("monkey {}: {}", idx, e)))
+            .collect::<Result<Vec<Monkey>, String>>()?;
+        if let Some((idx, monkey)) = monkeys
+            .iter()
+            .enumerate()
+            .find(|(_, monkey)| monkey.true_dest.max(monkey.false_dest) >= monkeys.len())
+        {
+            return Err(format!(
+                "monkey {}: throws to monkey {} but there are only {}",
+                idx,
+                monkey.true_dest.max(monkey.false_dest),
+                monkeys.len()
+            ));
+        }
+
+        Ok(Self {
+            monkeys,
+            rounds: 0,
+            throws: Throws::new(),
+        })
+    }
+
+    fn business(&self) -> usize {
+        let mut counts = self
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.items_inspected_count)
+            .collect::<Vec<usize>>();
+        counts.sort_by(|a, b| b.cmp(a));
+        counts.iter().take(2).product()
+    }
+}
+
+impl Session for MonkeySession {
+    fn step(&mut self, n: usize) -> Result<String, String> {
+        play_rounds(&mut self.monkeys, n)
+            .into_iter()
+            .for_each(|(key, count)| *self.throws.entry(key).or_insert(0) += count);
+        self.rounds += n;
+        Ok(format!("After round {}: monkey business {}", self.rounds, self.business()))
+    }
+
+    fn show(&self) -> String {
+        let mut res = vec![format!("After round {}:", self.rounds)];
+        self.monkeys.iter().enumerate().for_each(|(i, monkey)| {
+            res.push(format!(
+                "Monkey {} (inspected {:>6}): {}",
+                i,
+                monkey.items_inspected_count,
+                monkey.items.iter().map(|item| item.to_string()).collect::<Vec<String>>().join(", ")
+            ))
+        });
+        res.join("\n")
+    }
+
+    fn query(&self, args: &[&str]) -> Result<String, String> {
+        match args {
+            ["monkey", id] => id
+                .parse::<usize>()
+                .ok()
+                .and_then(|id| self.monkeys.get(id))
+                .map(|monkey| {
+                    format!(
+                        "items: {:?}\noperation: new = {:?}\ntest: {:?}\nthrows to {} if true, {} if false\ninspected {}",
+                        monkey.items,
+                        monkey.operation,
+                        monkey.test,
+                        monkey.true_dest,
+                        monkey.false_dest,
+                        monkey.items_inspected_count
+                    )
+                })
+                .ok_or_else(|| format!("No monkey {}", id)),
+            ["business"] => Ok(self.business().to_string()),
+            ["throws"] => {
+                let mut throws: Vec<(&(usize, usize), &usize)> = self.throws.iter().collect();
+                throws.sort();
+                Ok(throws
+                    .iter()
+                    .map(|((from, to), count)| format!("{} -> {}: {}", from, to, count))
+                    .collect::<Vec<String>>()
+                    .join("\n"))
+            }
+            _ => Err(format!("Unknown query: {}", args.join(" "))),
+        }
+    }
+
+    fn query_help(&self) -> Vec<String> {
+        vec![
+            "monkey N       items, rules and inspections of monkey N".to_owned(),
+            "business       product of the two highest inspection counts".to_owned(),
+            "throws         items thrown between monkeys so far".to_owned(),
+        ]
+    }
+}
+
+fn part1(data: &str) -> usize {
+    let mut monkeys = parse_monkeys(data);
+    play_rounds(&mut monkeys, 20);
+
+    let mut counts = monkeys
+        .iter()
+        .map(|monkey| monkey.items_inspected_count.clone())
+        .collect::<Vec<usize>>();
+
+    counts.sort_by(|a, b| b.cmp(a));
+    
+    counts.iter().take(2).into_iter().fold(1, |a, b| a * b)
+}
+
+fn part2(_data: &str) -> &str {
+    "NOT IMPLEMENTED!"
+}
+
+fn main() -> io::Result<()> {
+    if cli::flag("--repl") {
+        let data = match cli::value("--repl").as_deref() {
+            Some("test") => input!("day11.test").to_owned(),
+            _ => cli::input()?.unwrap_or_else(|| input!("day11.in").to_owned()),
+        };
+        return repl::run("day11", || MonkeySession::new(&data));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day11.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day11.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--dot") {
+        throw_graph(&input_data).save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn member(n: usize) -> String {
+        if n == 0 {
+            "old".to_owned()
+        } else {
+            n.to_string()
+        }
+    }
+
+    #[test]
+    fn operation_matches_formula() {
+        // `0` stands for `old`.
+        prop::check(
+            "parsed operations compute the written formula",
+            |rng| {
+                (
+                    rng.below(20) as usize,
+                    rng.bool(),
+                    rng.below(20) as usize,
+                    rng.below(1000) as usize,
+                )
+            },
+            |&(left, add, right, item)| {
+                let op = if add { "+" } else { "*" };
+                let operation = format!("{} {} {}", member(left), op, member(right))
+                    .parse::<Operation>()
+                    .unwrap();
+                let (left, right) = (
+                    if left == 0 { item } else { left },
+                    if right == 0 { item } else { right },
+                );
+                operation.exec(item) == if add { left + right } else { left * right }
+            },
+        );
+    }
+
+    #[test]
+    fn session_rejects_garbage() {
+        let error = |data: &str| MonkeySession::new(data).err();
+        let monkey = |op: &str, test: &str, dest: &str| {
+            format!(
+                "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = {}\n  Test: divisible by {}\n    \
+                 If true: throw to monkey {}\n    If false: throw to monkey 0\n",
+                op, test, dest
+            )
+        };
+        assert!(MonkeySession::new(&monkey("old * 19", "23", "0")).is_ok());
+        assert_eq!(error("garbage").as_deref(), Some("monkey 0: expected a number in \"garbage\""));
+        assert_eq!(error("Monkey 0:\n").as_deref(), Some("monkey 0: missing line 2 of \"Monkey 0:\""));
+        assert_eq!(
+            error(&monkey("old ^ 2", "23", "0")).as_deref(),
+            Some("monkey 0: unknown operator \"^\"")
+        );
+        assert_eq!(
+            error(&monkey("old * x", "23", "0")).as_deref(),
+            Some("monkey 0: invalid operand \"x\" in \"old * x\"")
+        );
+        assert_eq!(
+            error(&monkey("old * 19", "0", "0")).as_deref(),
+            Some("monkey 0: divisible by 0 in \"  Test: divisible by 0\"")
+        );
+        assert_eq!(
+            error(&monkey("old * 19", "23", "3")).as_deref(),
+            Some("monkey 0: throws to monkey 3 but there are only 1")
+        );
+    }
+
+    #[test]
+    fn divisible_test() {
+        prop::check(
+            "divisible by N checks item % N",
+            |rng| (1 + rng.below(30) as usize, rng.below(1000) as usize),
+            |&(n, item)| {
+                n == 0 || Operation::divisible(n).check(item) == (item % n == 0)
+            },
+        );
+    }
+}
@@ -1,6 +1,14 @@
 use std::{fmt::Debug, io, str::FromStr, string::ParseError, collections::VecDeque};
 
-const YEAR: &'static str = "2022";
+use aoc::{
+    bench,
+    cli,
+    input,
+    image::{Image, Rgb},
+    render::{Color, Table},
+    years,
+};
+
 const DAY: &'static str = "12";
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -17,49 +25,6 @@ impl Cell {
     }
 }
 
-fn draw_grid(
-    grid: &Vec<Vec<isize>>,
-    height: usize,
-    width: usize,
-    title: &str,
-) -> String {
-
-    let mut res: String = String::new();
-
-    let line_width = 4 * width + 2 + 3;
-    let header_trait_width = line_width 
-        - title.len() 
-        - (3 * 2) /* height & width */ 
-        - 4 /* space for title */
-        - 8 /* start trait */; 
-
-    res += &format!("┌");
-    for _ in 0..8 {
-        res += &format!( "─");
-    }
-    res += &format!( "  {}{: >3}x{: <3} ", title, width, height);
-    for _ in 0..header_trait_width {
-        res += &format!( "─");
-    }
-    res += &format!("┐\n");
-
-    for r in 0..height {
-        res += &format!( "│  ");
-        for c in 0..width {
-            res += &format!( "{: >4}", grid[r][c]);
-        }
-        res += &format!("   │\n");
-    }
-
-    res += &format!( "└");
-    for _ in 0..line_width {
-        res += &format!( "─");
-    }
-    res += &format!("┘\n");
-
-    res
-}
-
 struct Grid {
     width: usize,
     height: usize,
@@ -131,7 +96,7 @@ fn navigate(grid: &Grid, weights: &mut Grid, cell: Cell, curr_weight: usize) ->
     (res, false)
 }
 
-fn navigate_to_end(grid: &Grid, weights: &mut Grid, starts: Vec<Cell>) -> isize {
+fn navigate_to_end(grid: &Grid, weights: &mut Grid, starts: Vec<Cell>) -> (isize, Vec<Cell>) {
 
     let mut paths: VecDeque<Vec<Cell>> = starts.iter().map(|c| vec![*c]).collect();
 
@@ -173,12 +138,20 @@ fn navigate_to_end(grid: &Grid, weights: &mut Grid, starts: Vec<Cell>) -> isize
         }    
     }
 
-    weights.get_value(grid.end_cell)
+    (weights.get_value(grid.end_cell), paths.pop_front().unwrap_or_default())
 }
 
 impl Debug for Grid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", draw_grid(&self.grid, self.height, self.width, "Grid"))
+        let Cell(start_r, start_c) = self.start_cell;
+        let Cell(end_r, end_c) = self.end_cell;
+
+        let table = Table::grid(&format!("Grid {}x{}", self.width, self.height), &self.grid)
+            .with_row_indices()
+            .with_column_indices()
+            .highlight(start_r, start_c, Color::Green)
+            .highlight(end_r, end_c, Color::Red);
+        write!(f, "{}", table)
     }
 }
 
@@ -226,14 +199,19 @@ impl FromStr for Grid {
 fn part1(data: &str) -> isize {
     let grid: Grid = data.parse().unwrap();
     let weights: &mut Grid = &mut grid.init_weigths_grid();
-    // println!("{:?}", grid);
-    navigate_to_end(&grid, weights, vec![grid.start_cell.clone()])
+    if cli::verbose() {
+        eprintln!("{:?}", grid);
+    }
+    let (res, _) = navigate_to_end(&grid, weights, vec![grid.start_cell.clone()]);
+    if cli::verbose() {
+        eprintln!("{:?}", weights);
+    }
+    res
 }
 
 fn part2(data: &str) -> isize {
     let grid: Grid = data.parse().unwrap();
     let weights: &mut Grid = &mut grid.init_weigths_grid();
-    // println!("{:?}", grid);
     let mut starts = Vec::<Cell>::new();
     for r in 0..grid.height {
         for c in 0..grid.width {
@@ -243,25 +221,57 @@ fn part2(data: &str) -> isize {
             }
         }
     }
-    navigate_to_end(&grid, weights, starts)
+    let (res, _) = navigate_to_end(&grid, weights, starts);
+    if cli::verbose() {
+        eprintln!("{:?}", weights);
+    }
+    res
+}
+
+fn image(data: &str) -> Image {
+    let grid: Grid = data.parse().unwrap();
+    let weights: &mut Grid = &mut grid.init_weigths_grid();
+    let (_, path) = navigate_to_end(&grid, weights, vec![grid.start_cell.clone()]);
+
+    let mut image = Image::from_fn(grid.width, grid.height, |c, r| {
+        let elevation = match grid.get_value(Cell(r, c)) {
+            -1 => 1,
+            -2 => 26,
+            v => v,
+        };
+        Rgb::BLUE.blend(Rgb::WHITE, elevation as usize - 1, 25)
+    });
+    path.iter().for_each(|Cell(r, c)| image.set(*c, *r, Rgb::RED));
+    image.scale(6)
 }
 
 fn main() -> io::Result<()> {
     println!();
     println!(
         "---( AOC{} - Day {} )-----------------------[Rust]----",
-        YEAR, DAY
+        years::DEFAULT_YEAR, DAY
     );
 
-    let test_data = include_str!("day12.test");
-    println!("Test :: Part 1 ====>     {}", part1(&test_data));
-    println!("Test :: Part 2 ====>     {}", part2(&test_data));
-    println!("--------------------------------------------------------");
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day12.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
 
-    let input_data = include_str!("day12.in");
-    println!("Input:: Part 1 ====>     {}", part1(&input_data));
-    println!("Input:: Part 2 ====>     {}", part2(&input_data));
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day12.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
     println!("--------------------------------------------------------");
 
+    if let Some(path) = cli::value("--image") {
+        image(&input_data).save(path)?;
+    }
+
     Ok(())
 }
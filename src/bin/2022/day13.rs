@@ -1,6 +1,7 @@
-use std::{cmp::Ordering, fmt::Debug, io, num::ParseIntError, str::FromStr};
+use std::{cmp::Ordering, fmt::{Debug, Display}, io, num::ParseIntError, str::FromStr};
+
+use aoc::{bench, cli, input, years};
 
-const YEAR: &'static str = "2022";
 const DAY: &'static str = "13";
 
 #[derive(Clone, Eq, PartialEq)]
@@ -90,6 +91,19 @@ impl Debug for Node {
     }
 }
 
+/// Packet syntax, as read by `from_str`.
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Number(n) => write!(f, "{}", n),
+            Node::List(childs) => {
+                let childs: Vec<String> = childs.iter().map(|child| child.to_string()).collect();
+                write!(f, "[{}]", childs.join(","))
+            }
+        }
+    }
+}
+
 impl FromStr for Node {
     type Err = ParseIntError;
 
@@ -190,18 +204,98 @@ fn main() -> io::Result<()> {
     println!();
     println!(
         "---( AOC{} - Day {} )-----------------------[Rust]----",
-        YEAR, DAY
+        years::DEFAULT_YEAR, DAY
     );
 
-    let test_data = include_str!("day13.test");
-    println!("Test :: Part 1 ====>     {}", part1(&test_data));
-    println!("Test :: Part 2 ====>     {}", part2(&test_data));
-    println!("--------------------------------------------------------");
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day13.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
 
-    let input_data = include_str!("day13.in");
-    println!("Input:: Part 1 ====>     {}", part1(&input_data));
-    println!("Input:: Part 2 ====>     {}", part2(&input_data));
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day13.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
     println!("--------------------------------------------------------");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop::{self, Rng, Shrink};
+
+    impl Shrink for Node {
+        fn shrink(&self) -> Vec<Self> {
+            match self {
+                Node::Number(n) => n.shrink().into_iter().map(Node::Number).collect(),
+                Node::List(childs) => {
+                    let mut res: Vec<Node> = childs
+                        .iter()
+                        .filter(|c| matches!(c, Node::List(_)))
+                        .cloned()
+                        .collect();
+                    res.extend(childs.shrink().into_iter().map(Node::List));
+                    res
+                }
+            }
+        }
+    }
+
+    fn node(rng: &mut Rng, depth: usize) -> Node {
+        if depth == 0 || rng.below(3) == 0 {
+            Node::Number(rng.below(12) as usize)
+        } else {
+            Node::List(rng.vec(4, |rng| node(rng, depth - 1)))
+        }
+    }
+
+    fn packet(rng: &mut Rng) -> Node {
+        Node::List(rng.vec(4, |rng| node(rng, 3)))
+    }
+
+    fn is_packet(node: &Node) -> bool {
+        matches!(node, Node::List(_))
+    }
+
+    #[test]
+    fn parse_format_round_trip() {
+        prop::check("format then parse gives the packet back", packet, |p| {
+            !is_packet(p) || p.to_string().parse::<Node>().unwrap() == *p
+        });
+    }
+
+    #[test]
+    fn ord_is_reflexive_and_antisymmetric() {
+        prop::check(
+            "a == a, a.cmp(b) == b.cmp(a).reverse()",
+            |rng| (packet(rng), packet(rng)),
+            |(a, b)| a.cmp(a) == Ordering::Equal && a.cmp(b) == b.cmp(a).reverse(),
+        );
+    }
+
+    #[test]
+    fn ord_is_transitive() {
+        prop::check(
+            "a <= b <= c implies a <= c",
+            |rng| (packet(rng), packet(rng), packet(rng)),
+            |(a, b, c)| !(a <= b && b <= c) || a <= c,
+        );
+    }
+
+    #[test]
+    fn ord_agrees_with_eq() {
+        prop::check(
+            "structurally equal packets compare Equal",
+            |rng| (node(rng, 2), node(rng, 2)),
+            |(a, b)| a.cmp(&a.clone()) == Ordering::Equal && (a != b || a.cmp(b) == Ordering::Equal),
+        );
+    }
+}
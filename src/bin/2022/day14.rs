@@ -0,0 +1,590 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    io,
+    str::FromStr,
+    string::ParseError,
+};
+
+use aoc::{
+    animation::{Animation, Player},
+    bench,
+    cli,
+    input,
+    image::{Image, Rgb},
+    render::{Color, Table},
+    svg::{Style, Svg, Viewport},
+    years,
+};
+
+const DAY: &'static str = "14";
+
+type Lines = HashMap<usize, HashSet<Range>>;
+
+#[derive(Clone)]
+enum LineType {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    fn get_by_line_type(&self, line_type: LineType) -> usize {
+        match line_type {
+            LineType::Vertical => self.x,
+            LineType::Horizontal => self.y,
+        }
+    }
+
+    fn get_oposite_line_type(&self, line_type: LineType) -> usize {
+        match line_type {
+            LineType::Vertical => self.y,
+            LineType::Horizontal => self.x,
+        }
+    }
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split(",").collect();
+        Ok(Point {
+            x: parts.get(0).unwrap().parse().unwrap(),
+            y: parts.get(1).unwrap().parse().unwrap(),
+        })
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+struct Range {
+    min: usize,
+    max: usize,
+}
+
+impl Range {
+    fn new(a: usize, b: usize) -> Self {
+        let min = a.min(b);
+        let max = a.max(b);
+        Self { min, max }
+    }
+
+    fn contains(&self, n: usize) -> bool {
+        n >= self.min && n <= self.max
+    }
+}
+
+impl Debug for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+#[derive(Debug)]
+struct Path {
+    vertical_lines: Lines,
+    horizontal_lines: Lines,
+    sands: HashSet<Point>,
+    deepest_line: usize,
+    with_floor: bool,
+}
+
+impl Path {
+    fn build_lines(edges: &Vec<Point>, line_type: LineType) -> (usize, Lines) {
+        edges
+            .windows(2)
+            .map(|windows| (&windows[0], &windows[1]))
+            .filter(|(a, b)| {
+                a.get_by_line_type(line_type.clone()) == b.get_by_line_type(line_type.clone())
+            })
+            .map(|(a, b)| {
+                (
+                    a.get_by_line_type(line_type.clone()),
+                    Range::new(
+                        a.get_oposite_line_type(line_type.clone()),
+                        b.get_oposite_line_type(line_type.clone()),
+                    ),
+                )
+            })
+            .fold(
+                (0, HashMap::<usize, HashSet<Range>>::new()),
+                |(max, mut map), (idx, range)| {
+                    map.entry(idx)
+                        .and_modify(|set| {
+                            set.insert(range.clone());
+                        })
+                        .or_insert(HashSet::from([range]));
+                    (idx.max(max), map)
+                },
+            )
+    }
+
+    fn new() -> Self {
+        Self {
+            vertical_lines: HashMap::new(),
+            horizontal_lines: HashMap::new(),
+            sands: HashSet::new(),
+            deepest_line: 0,
+            with_floor: false,
+        }
+    }
+
+    fn extend(&mut self, other: &Self) {
+        other.vertical_lines.iter().for_each(|(y, ranges)| {
+            let res_ranges = self.vertical_lines.entry(*y).or_insert(HashSet::new());
+            ranges.iter().for_each(|range| {
+                res_ranges.insert(range.clone());
+            });
+        });
+        other.horizontal_lines.iter().for_each(|(y, ranges)| {
+            let res_ranges = self.horizontal_lines.entry(*y).or_insert(HashSet::new());
+            ranges.iter().for_each(|range| {
+                res_ranges.insert(range.clone());
+            });
+        });
+        self.deepest_line = self.deepest_line.max(other.deepest_line);
+    }
+
+    fn point_is_empty(&self, point: &Point) -> bool {
+        if self.sands.contains(point) {
+            false
+        } else {
+            let vertical_value = point.get_by_line_type(LineType::Vertical);
+            let horizontal_value = point.get_by_line_type(LineType::Horizontal);
+
+            let vertical_contains = self.vertical_lines.contains_key(&vertical_value)
+                && self
+                    .vertical_lines
+                    .get(&vertical_value)
+                    .unwrap()
+                    .iter()
+                    .any(|range| range.contains(horizontal_value));
+
+            let horizontal_contains = self.horizontal_lines.contains_key(&horizontal_value)
+                && self
+                    .horizontal_lines
+                    .get(&horizontal_value)
+                    .unwrap()
+                    .iter()
+                    .any(|range| range.contains(vertical_value));
+
+            !vertical_contains && !horizontal_contains
+        }
+    }
+
+    fn next_point(&self, point: &Point) -> Option<Point> {
+        if self.sands.contains(&point) {
+            None
+        } else if self.with_floor && point.y >= self.deepest_line + 1 {
+            None
+        } else if !self.with_floor && point.y >= self.deepest_line {
+            None
+        } else {
+            let Point { x, y } = point;
+
+            [
+                Point::new(*x, *y + 1),
+                Point::new(x - 1, y + 1),
+                Point::new(x + 1, y + 1),
+            ]
+            .iter()
+            .map(|p| *p)
+            .find(|next_point| self.point_is_empty(next_point))
+        }
+    }
+
+    fn add_point_rest(&mut self, point: &Point) -> Option<Point> {
+        let mut next_point = point.clone();
+
+        loop {
+            match self.next_point(&next_point) {
+                Some(p) => {
+                    next_point = p;
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        if !self.with_floor && next_point.y >= self.deepest_line {
+            None
+        } else if self.sands.contains(&next_point) {
+            None
+        } else {
+            self.sands.insert(next_point.clone());
+            Some(next_point)
+        }
+    }
+
+    /// Map of the cave as `#` rock, `o` sand, `+` source and `.` air, with
+    /// the x coordinate of its first column.
+    fn cells(&self) -> (usize, Vec<Vec<char>>) {
+        let floor = self.deepest_line + 2;
+        let xs = self
+            .horizontal_lines
+            .values()
+            .flatten()
+            .flat_map(|range| [range.min, range.max])
+            .chain(self.vertical_lines.keys().copied())
+            .chain(self.sands.iter().map(|point| point.x))
+            .chain([500]);
+        let (min_x, max_x) = xs.fold((usize::MAX, 0), |(min, max), x| (min.min(x), max.max(x)));
+        let (min_x, max_x) = (min_x.saturating_sub(1), max_x + 1);
+        let max_y = if self.with_floor { floor } else { self.deepest_line };
+
+        let grid: Vec<Vec<char>> = (0..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| {
+                        let point = Point::new(x, y);
+                        if self.sands.contains(&point) {
+                            'o'
+                        } else if (x, y) == (500, 0) {
+                            '+'
+                        } else if (self.with_floor && y == floor) || !self.point_is_empty(&point) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        (min_x, grid)
+    }
+
+    fn draw(&self, title: &str, falling: Option<Point>) -> Table {
+        let (min_x, mut grid) = self.cells();
+        let sands = self.sands.iter().map(|point| (point.y, point.x - min_x));
+
+        let falling = falling.filter(|p| p.x >= min_x && p.x - min_x < grid[0].len() && p.y < grid.len());
+        if let Some(p) = falling {
+            grid[p.y][p.x - min_x] = 'o';
+        }
+
+        let table = Table::grid(title, &grid)
+            .spacing(0)
+            .highlight_all(sands, Color::Yellow);
+        match falling {
+            Some(p) => table.highlight(p.y, p.x - min_x, Color::Red),
+            None => table,
+        }
+    }
+
+    fn svg(&self, viewport: Option<Viewport>) -> Svg {
+        let (min_x, grid) = self.cells();
+        let viewport = viewport.unwrap_or_else(|| {
+            Viewport::new(min_x as f64, 0.0, (min_x + grid[0].len()) as f64, grid.len() as f64)
+        });
+
+        let mut svg = Svg::new(viewport, 1000.0);
+        svg.rect(
+            (viewport.min_x, viewport.min_y),
+            viewport.width(),
+            viewport.height(),
+            &Style::fill("black"),
+        );
+
+        let rock = Style::fill("gray");
+        self.vertical_lines.iter().for_each(|(x, ranges)| {
+            ranges.iter().for_each(|range| {
+                svg.rect((*x as f64, range.min as f64), 1.0, (range.max - range.min + 1) as f64, &rock);
+            });
+        });
+        self.horizontal_lines.iter().for_each(|(y, ranges)| {
+            ranges.iter().for_each(|range| {
+                svg.rect((range.min as f64, *y as f64), (range.max - range.min + 1) as f64, 1.0, &rock);
+            });
+        });
+        if self.with_floor {
+            svg.rect(
+                (min_x as f64, (self.deepest_line + 2) as f64),
+                grid[0].len() as f64,
+                1.0,
+                &rock,
+            );
+        }
+
+        let sand = Style::fill("gold");
+        self.sands.iter().for_each(|point| {
+            svg.rect((point.x as f64, point.y as f64), 1.0, 1.0, &sand);
+        });
+        svg.dot((500.5, 0.5), 4.0, &Style::fill("red"));
+        svg
+    }
+
+    fn image(&self) -> Image {
+        let (_, grid) = self.cells();
+
+        Image::from_fn(grid[0].len(), grid.len(), |x, y| match grid[y][x] {
+            '#' => Rgb::GRAY,
+            'o' => Rgb::YELLOW,
+            '+' => Rgb::RED,
+            _ => Rgb::BLACK,
+        })
+        .scale(3)
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let edges = s
+            .trim()
+            .split(" -> ")
+            .map(|s| s.parse::<Point>().unwrap())
+            .collect::<Vec<Point>>();
+
+        let (_, vertical_lines) = Path::build_lines(&edges, LineType::Vertical);
+        let (deepest_line, horizontal_lines) = Path::build_lines(&edges, LineType::Horizontal);
+
+        Ok(Path {
+            vertical_lines,
+            horizontal_lines,
+            deepest_line,
+            sands: HashSet::new(),
+            with_floor: false,
+        })
+    }
+}
+
+struct SandAnimation {
+    path: Path,
+    grain: Option<Point>,
+}
+
+impl SandAnimation {
+    fn new(data: &str) -> Self {
+        let mut path = Path::new();
+
+        data.lines()
+            .map(|s| s.parse::<Path>().unwrap())
+            .for_each(|p| path.extend(&p));
+
+        Self { path, grain: None }
+    }
+}
+
+impl Animation for SandAnimation {
+    fn title(&self) -> String {
+        format!("AOC{} - Day {} :: {} grains at rest", years::DEFAULT_YEAR, DAY, self.path.sands.len())
+    }
+
+    fn frame(&self) -> String {
+        self.path.draw("Sand", self.grain).render()
+    }
+
+    // Same moves as `add_point_rest`, one cell at a time.
+    fn advance(&mut self) -> bool {
+        let start_sand = Point::new(500, 0);
+        let Some(grain) = self.grain else {
+            self.grain = Some(start_sand);
+            return !self.path.sands.contains(&start_sand);
+        };
+
+        match self.path.next_point(&grain) {
+            Some(p) => {
+                self.grain = Some(p);
+                true
+            }
+            None if !self.path.with_floor && grain.y >= self.path.deepest_line => false,
+            None => {
+                self.path.sands.insert(grain);
+                self.grain = None;
+                true
+            }
+        }
+    }
+}
+
+fn part1(data: &str) -> usize {
+    let mut all_path = Path::new();
+
+    data.lines()
+        .map(|s| s.parse::<Path>().unwrap())
+        .for_each(|path| all_path.extend(&path));
+
+    let start_sand = Point::new(500, 0);
+    while all_path.add_point_rest(&start_sand) != None {}
+
+    if cli::verbose() {
+        eprintln!("{}", all_path.draw("Sand", None));
+    }
+    all_path.sands.len()
+}
+
+fn part2(data: &str) -> usize {
+    let mut all_path = Path::new();
+
+    data.lines()
+        .map(|s| s.parse::<Path>().unwrap())
+        .for_each(|path| all_path.extend(&path));
+    all_path.with_floor = true;
+
+    let start_sand = Point::new(500, 0);
+    while let Some(_) = all_path.add_point_rest(&start_sand) {}
+
+    if cli::verbose() {
+        eprintln!("{}", all_path.draw("Sand with floor", None));
+    }
+    all_path.sands.len()
+}
+
+fn image(data: &str) -> Image {
+    let mut all_path = Path::new();
+
+    data.lines()
+        .map(|s| s.parse::<Path>().unwrap())
+        .for_each(|path| all_path.extend(&path));
+    all_path.with_floor = true;
+
+    let start_sand = Point::new(500, 0);
+    while let Some(_) = all_path.add_point_rest(&start_sand) {}
+
+    all_path.image()
+}
+
+fn svg(data: &str) -> Svg {
+    let mut all_path = Path::new();
+
+    data.lines()
+        .map(|s| s.parse::<Path>().unwrap())
+        .for_each(|path| all_path.extend(&path));
+    all_path.with_floor = true;
+
+    let start_sand = Point::new(500, 0);
+    while let Some(_) = all_path.add_point_rest(&start_sand) {}
+
+    let viewport = cli::value("--viewport").map(|v| v.parse::<Viewport>().expect("Invalid --viewport!"));
+    all_path.svg(viewport)
+}
+
+fn main() -> io::Result<()> {
+    if cli::flag("--animate") {
+        let data = match cli::value("--animate").as_deref() {
+            Some("test") => input!("day14.test"),
+            _ => input!("day14.in"),
+        };
+        return Player::from_cli().play(&mut SandAnimation::new(data));
+    }
+
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day14.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day14.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--image") {
+        image(&input_data).save(path)?;
+    }
+
+    if let Some(path) = cli::value("--svg") {
+        svg(&input_data).save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    #[test]
+    fn point_round_trip() {
+        prop::check(
+            "x,y parses back to the point",
+            |rng| (rng.below(1000) as usize, rng.below(1000) as usize),
+            |&(x, y)| format!("{},{}", x, y).parse::<Point>().unwrap() == Point::new(x, y),
+        );
+    }
+
+    #[test]
+    fn range_contains_its_bounds() {
+        prop::check(
+            "ranges hold exactly the values between their ends",
+            |rng| {
+                (
+                    rng.below(50) as usize,
+                    rng.below(50) as usize,
+                    rng.below(50) as usize,
+                )
+            },
+            |&(a, b, n)| Range::new(a, b).contains(n) == (a.min(b) <= n && n <= a.max(b)),
+        );
+    }
+
+    #[test]
+    fn sand_stays_in_the_cave() {
+        prop::check(
+            "rested sand never overlaps rock",
+            |rng| {
+                rng.vec(4, |rng| {
+                    (
+                        490 + rng.below(20) as usize,
+                        1 + rng.below(10) as usize,
+                        rng.below(6) as usize,
+                        rng.bool(),
+                    )
+                })
+            },
+            |walls| {
+                if walls.is_empty() {
+                    return true;
+                }
+                let data: Vec<String> = walls
+                    .iter()
+                    .map(|&(x, y, len, vertical)| {
+                        let (x2, y2) = if vertical { (x, y + len) } else { (x + len, y) };
+                        format!("{},{} -> {},{}", x, y, x2, y2)
+                    })
+                    .collect();
+                let mut cave = Path::new();
+                data.iter()
+                    .for_each(|line| cave.extend(&line.parse::<Path>().unwrap()));
+                let rocks: Vec<Point> = (480..=520)
+                    .flat_map(|x| (0..=20).map(move |y| Point::new(x, y)))
+                    .filter(|p| !cave.point_is_empty(p))
+                    .collect();
+
+                while cave.add_point_rest(&Point::new(500, 0)).is_some() {}
+                cave.sands.iter().all(|sand| !rocks.contains(sand))
+            },
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use std::{collections::HashSet, fmt::Debug, io, str::FromStr, string::ParseError};
+
+use aoc::{
+    bench,
+    cli,
+    input,
+    svg::{Style, Svg, Viewport},
+    years,
+};
+
+const DAY: &'static str = "01";
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    fn distance(&self, point: &Point) -> isize {
+        (self.x - point.x).abs() + (self.y - point.y).abs()
+    }
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone)]
+struct Sensor {
+    position: Point,
+    first_beacon: Point,
+    min_distance: isize,
+}
+
+impl Sensor {
+    fn distance(&self, point: &Point) -> isize {
+        self.position.distance(point)
+    }
+
+    fn is_in_no_beacon_zone(&self, point: &Point) -> bool {
+        self.min_distance >= self.distance(point)
+    }
+}
+
+impl Debug for Sensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{:?} -> {:?} | {}]",
+            self.position, self.first_beacon, self.min_distance
+        )
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s
+            .replace("Sensor at x=", "")
+            .replace(" y=", "")
+            .replace(": closest beacon is at x=", ",");
+
+        let numbers = line
+            .split(",")
+            .map(|n| n.parse::<isize>().unwrap())
+            .collect::<Vec<isize>>();
+
+        let position = Point {
+            x: numbers[0],
+            y: numbers[1],
+        };
+
+        let first_beacon = Point {
+            x: numbers[2],
+            y: numbers[3],
+        };
+
+        let min_distance = first_beacon.distance(&position);
+
+        Ok(Self {
+            position,
+            first_beacon,
+            min_distance,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Puzzle {
+    sensors: Vec<Sensor>,
+    left_top: Point,
+    right_buttom: Point,
+    _positions: HashSet<Point>,
+    _beacons: HashSet<Point>,
+}
+
+impl Puzzle {
+    fn from(sensors: Vec<Sensor>) -> Self {
+        let mut sensors = sensors;
+        let mut puzzle = Self::init(sensors.pop().unwrap());
+
+        while let Some(sensor) = sensors.pop() {
+            puzzle.add_sensor(sensor);
+        }
+
+        puzzle
+    }
+
+    fn init(sensor: Sensor) -> Self {
+        Puzzle {
+            sensors: vec![sensor.clone()],
+            left_top: Point {
+                x: sensor.position.x - sensor.min_distance,
+                y: sensor.position.y - sensor.min_distance,
+            },
+            right_buttom: Point {
+                x: sensor.position.x + sensor.min_distance,
+                y: sensor.position.y + sensor.min_distance,
+            },
+            _positions: HashSet::from([sensor.position]),
+            _beacons: HashSet::from([sensor.first_beacon]),
+        }
+    }
+
+    fn add_sensor(&mut self, sensor: Sensor) {
+        self.left_top = Point {
+            x: self.left_top.x.min(sensor.position.x - sensor.min_distance),
+            y: self.left_top.y.min(sensor.position.y - sensor.min_distance),
+        };
+
+        self.right_buttom = Point {
+            x: self
+                .right_buttom
+                .x
+                .max(sensor.position.x + sensor.min_distance),
+            y: self
+                .right_buttom
+                .y
+                .max(sensor.position.y + sensor.min_distance),
+        };
+
+        self.sensors.push(sensor.clone());
+        self._positions.insert(sensor.position);
+        self._beacons.insert(sensor.first_beacon);
+    }
+
+    fn is_in_no_beacon_zone(&self, point: &Point) -> bool {
+        for sensor in &self.sensors {
+            if sensor.is_in_no_beacon_zone(&point) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Where to mark the distress beacon on the drawing: the only point of
+    /// the search area out of reach of every sensor has to lie just outside
+    /// the zone of one of them.
+    fn distress_beacon(&self, max: isize) -> Option<Point> {
+        self.sensors.iter().find_map(|sensor| {
+            let distance = sensor.min_distance + 1;
+            let Point { x, y } = sensor.position;
+
+            (0..=distance)
+                .flat_map(|dx| {
+                    let dy = distance - dx;
+                    [(x + dx, y + dy), (x + dx, y - dy), (x - dx, y + dy), (x - dx, y - dy)]
+                })
+                .map(|(x, y)| Point { x, y })
+                .find(|point| {
+                    (0..=max).contains(&point.x)
+                        && (0..=max).contains(&point.y)
+                        && !self.is_in_no_beacon_zone(point)
+                })
+        })
+    }
+
+    fn draw(&self, max: isize, viewport: Option<Viewport>) -> Svg {
+        let corners = |sensor: &Sensor| -> Vec<(f64, f64)> {
+            let Point { x, y } = sensor.position;
+            let d = sensor.min_distance;
+            [(x, y - d), (x + d, y), (x, y + d), (x - d, y)]
+                .iter()
+                .map(|(x, y)| (*x as f64, *y as f64))
+                .collect()
+        };
+
+        let viewport = viewport.unwrap_or_else(|| Viewport::around(self.sensors.iter().flat_map(corners)));
+        let mut svg = Svg::new(viewport, 1000.0);
+        svg.rect(
+            (viewport.min_x, viewport.min_y),
+            viewport.width(),
+            viewport.height(),
+            &Style::fill("white"),
+        );
+
+        self.sensors.iter().for_each(|sensor| {
+            svg.polygon(
+                &corners(sensor),
+                &Style::fill("steelblue").with_opacity(0.25).with_stroke("steelblue", 1.0),
+            );
+        });
+        svg.rect((0.0, 0.0), max as f64, max as f64, &Style::stroke("black", 1.5));
+
+        self.sensors.iter().for_each(|sensor| {
+            let Point { x, y } = sensor.position;
+            svg.dot((x as f64, y as f64), 3.0, &Style::fill("navy"));
+        });
+        self._beacons.iter().for_each(|Point { x, y }| {
+            svg.dot((*x as f64, *y as f64), 3.0, &Style::fill("darkgreen"));
+        });
+
+        if let Some(Point { x, y }) = self.distress_beacon(max) {
+            let at = (x as f64, y as f64);
+            svg.dot(at, 6.0, &Style::fill("red").with_stroke("black", 1.0));
+            svg.text(at, 14.0, &format!(" distress beacon ({}, {})", x, y), &Style::fill("red"));
+        }
+        svg
+    }
+}
+
+fn part1(data: &str, y: isize) -> usize {
+    let sensors = data
+        .lines()
+        .map(|line| line.parse::<Sensor>().unwrap())
+        .collect::<Vec<Sensor>>();
+    let puzzle = Puzzle::from(sensors);
+
+    let points = (puzzle.left_top.x..=puzzle.right_buttom.x)
+        .map(|x| Point { x, y })
+        .filter(|point| !puzzle._beacons.contains(point) && puzzle.is_in_no_beacon_zone(point))
+        .collect::<Vec<Point>>();
+
+    points.len()
+}
+
+fn part2(data: &str, max: isize) -> isize {
+    let sensors = data
+        .lines()
+        .map(|line| line.parse::<Sensor>().unwrap())
+        .collect::<Vec<Sensor>>();
+    let puzzle = Puzzle::from(sensors);
+
+    // println!("{:#?}", puzzle);
+
+    for x in 0..=max {
+        for y in 0..=max {
+            let point = Point {x,y};
+            if !puzzle.is_in_no_beacon_zone(&point) {
+                println!("{:?}", point);
+                return point.x * 4000000 + point.y;
+            }
+        }
+    }
+
+    0
+}
+
+fn svg(data: &str, max: isize) -> Svg {
+    let sensors = data
+        .lines()
+        .map(|line| line.parse::<Sensor>().unwrap())
+        .collect::<Vec<Sensor>>();
+    let viewport = cli::value("--viewport").map(|v| v.parse::<Viewport>().expect("Invalid --viewport!"));
+
+    Puzzle::from(sensors).draw(max, viewport)
+}
+
+fn main() -> io::Result<()> {
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day15.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data, 10));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data, 20));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day15.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data, 2000000)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data, 4000000)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--svg") {
+        svg(&input_data, 4000000).save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    fn coordinate(rng: &mut prop::Rng) -> i64 {
+        rng.range(-4_000_000..=4_000_000)
+    }
+
+    #[test]
+    fn sensor_parse() {
+        prop::check(
+            "sensor lines parse back with the manhattan distance",
+            |rng| {
+                (
+                    coordinate(rng),
+                    coordinate(rng),
+                    coordinate(rng),
+                    coordinate(rng),
+                )
+            },
+            |&(sx, sy, bx, by)| {
+                let line = format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sx, sy, bx, by
+                );
+                let sensor = line.parse::<Sensor>().unwrap();
+                sensor.position
+                    == Point {
+                        x: sx as isize,
+                        y: sy as isize,
+                    }
+                    && sensor.first_beacon
+                        == Point {
+                            x: bx as isize,
+                            y: by as isize,
+                        }
+                    && sensor.min_distance == ((sx - bx).abs() + (sy - by).abs()) as isize
+            },
+        );
+    }
+
+    #[test]
+    fn beacon_is_in_its_sensor_zone() {
+        prop::check(
+            "the closest beacon sits on the edge of the sensor zone",
+            |rng| {
+                (
+                    coordinate(rng),
+                    coordinate(rng),
+                    coordinate(rng),
+                    coordinate(rng),
+                )
+            },
+            |&(sx, sy, bx, by)| {
+                let sensor = format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sx, sy, bx, by
+                )
+                .parse::<Sensor>()
+                .unwrap();
+                let beyond = Point {
+                    x: sensor.first_beacon.x + (bx - sx).signum() as isize,
+                    y: sensor.first_beacon.y,
+                };
+                sensor.is_in_no_beacon_zone(&sensor.first_beacon)
+                    && (bx == sx || !sensor.is_in_no_beacon_zone(&beyond))
+            },
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::{collections::HashMap, fmt::Debug, io, str::FromStr, string::ParseError};
+
+use aoc::{bitset::BitSet, bench, cli, input, dot::Dot, memo::Memo, years};
+
+const DAY: &'static str = "16";
+
+type Rates = HashMap<String, usize>;
+type Key = (usize, usize, usize, BitSet);
+type Cache = Memo<Key, usize>;
+
+#[derive(Clone)]
+struct Node {
+    id: String,
+    idx: usize,
+    rate: usize,
+    next: Vec<String>,
+}
+
+struct Graph(HashMap<String, Node>);
+
+impl Graph {
+    fn new() -> Graph {
+        Graph(HashMap::new())
+    }
+
+    fn next(&self, el: &str) -> Vec<String> {
+        self.0
+            .get(el)
+            .map(|node| node.next.iter().map(|n| n.clone()).collect::<Vec<String>>())
+            .unwrap_or(vec![])
+    }
+
+    fn idx(&self, el: &str) -> usize {
+        self.0.get(el).expect("Unknown valve!").idx
+    }
+
+    fn init_rates(&self) -> Rates {
+        self.0
+            .values()
+            .map(|node| (node.id.clone(), node.rate.clone()))
+            .filter(|(_, rate)| *rate > 0)
+            .collect()
+    }
+}
+
+impl FromIterator<Node> for Graph {
+    fn from_iter<T: IntoIterator<Item = Node>>(iter: T) -> Self {
+        iter.into_iter().fold(Graph::new(), |mut graph, mut node| {
+            node.idx = graph.0.len();
+            graph.0.insert(node.id.clone(), node);
+            graph
+        })
+    }
+}
+
+impl Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({},{: >4}) -> [{}]",
+            self.id,
+            self.rate,
+            self.next.join(", ")
+        )
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .replace("Valve ", "")
+            .replace(" has flow rate=", "|")
+            .replace("; tunnels lead to valves ", "|")
+            .replace("; tunnel leads to valve ", "|");
+
+        let mut tokens: Vec<&str> = s.split("|").collect();
+        let next: Vec<String> = tokens
+            .pop()
+            .unwrap()
+            .split(", ")
+            .map(|t| t.to_string())
+            .collect();
+
+        let rate: usize = tokens.pop().unwrap().parse().unwrap();
+
+        Ok(Self {
+            id: tokens[0].into(),
+            idx: 0,
+            rate,
+            next,
+        })
+    }
+}
+
+fn build_key(graph: &Graph, curr_node: &str, time: &usize, opened: &BitSet, elephant_num: &usize) -> Key {
+    (*elephant_num, graph.idx(curr_node), *time, opened.clone())
+}
+
+fn find_path(
+    graph: &Graph,
+    curr_node: &str,
+    rates: &Rates,
+    opened: BitSet,
+    time: usize,
+    cache: &mut Cache,
+    elephant_num: &usize,
+) -> usize {
+    let key = build_key(graph, curr_node, &time, &opened, elephant_num);
+    if let Some(pressure) = cache.get(&key) {
+        return pressure;
+    }
+
+    if time == 0 {
+        return if *elephant_num == 1 {
+            0
+        } else {
+            find_path(
+                graph,
+                "AA",
+                rates,
+                opened.clone(),
+                26,
+                cache,
+                &(elephant_num - 1),
+            )
+        };
+    }
+
+    let mut max_presure = graph
+        .next(curr_node)
+        .iter()
+        .map(|next_node| {
+            find_path(
+                graph,
+                &next_node,
+                rates,
+                opened.clone(),
+                time - 1,
+                cache,
+                elephant_num,
+            )
+        })
+        .max()
+        .unwrap();
+
+    if time >= 2 {
+        max_presure = max_presure.max(
+            graph
+                .next(curr_node)
+                .iter()
+                .filter(|next_node| {
+                    !opened.contains(graph.idx(next_node)) && rates.contains_key(next_node.clone())
+                })
+                .map(|next_node| {
+                    let pressure = rates.get(next_node).unwrap();
+
+                    let mut opened: BitSet = opened.clone();
+                    opened.insert(graph.idx(next_node));
+
+                    (pressure * (time - 2))
+                        + find_path(
+                            graph,
+                            &next_node,
+                            rates,
+                            opened,
+                            time - 2,
+                            cache,
+                            elephant_num,
+                        )
+                })
+                .max()
+                .unwrap_or(0),
+        );
+    }
+
+    cache.insert(key, max_presure);
+    max_presure
+}
+
+/// Valves visited by a single actor on a best path, each with the minute it
+/// was opened at, if it was.
+fn best_route(graph: &Graph, rates: &Rates, cache: &mut Cache, time: usize) -> Vec<(String, Option<usize>)> {
+    let total_time = time;
+    let mut time = time;
+    let mut curr_node = "AA".to_owned();
+    let mut opened = BitSet::new();
+    let mut remaining = find_path(graph, &curr_node, rates, opened.clone(), time, cache, &1);
+    let mut route = vec![(curr_node.clone(), None)];
+
+    while remaining > 0 {
+        let open_step = graph.next(&curr_node).into_iter().find_map(|next_node| {
+            let rate = rates.get(&next_node)?;
+            if time < 2 || opened.contains(graph.idx(&next_node)) {
+                return None;
+            }
+            let mut next_opened = opened.clone();
+            next_opened.insert(graph.idx(&next_node));
+            let rest = find_path(graph, &next_node, rates, next_opened.clone(), time - 2, cache, &1);
+            (rate * (time - 2) + rest == remaining).then_some((next_node, Some(next_opened), rest))
+        });
+        let step = open_step.or_else(|| {
+            graph.next(&curr_node).into_iter().find_map(|next_node| {
+                let rest = find_path(graph, &next_node, rates, opened.clone(), time - 1, cache, &1);
+                (rest == remaining).then_some((next_node, None, rest))
+            })
+        });
+
+        let (next_node, next_opened, rest) = step.expect("No step leads to the best pressure!");
+        match next_opened {
+            Some(next_opened) => {
+                time -= 2;
+                opened = next_opened;
+                route.push((next_node.clone(), Some(total_time - time)));
+            }
+            None => {
+                time -= 1;
+                route.push((next_node.clone(), None));
+            }
+        }
+        curr_node = next_node;
+        remaining = rest;
+    }
+
+    route
+}
+
+fn valves_graph(data: &str) -> Dot {
+    let graph: Graph = data
+        .lines()
+        .map(|line| line.parse::<Node>().unwrap())
+        .collect();
+    let rates = graph.init_rates();
+    let route = best_route(&graph, &rates, &mut Cache::new(), 30);
+
+    let mut ids: Vec<&String> = graph.0.keys().collect();
+    ids.sort();
+
+    let mut dot = Dot::graph("valves");
+    ids.iter().for_each(|id| {
+        let node = &graph.0[*id];
+        let opened_at = route.iter().find_map(|(valve, minute)| minute.filter(|_| valve == *id));
+        let label = match opened_at {
+            Some(minute) => format!("{}\nrate {}\nopened at {}", id, node.rate, minute),
+            None => format!("{}\nrate {}", id, node.rate),
+        };
+        let style = if opened_at.is_some() { "filled" } else if node.rate > 0 { "bold" } else { "solid" };
+        dot.add_node(id, &[("label", &label), ("style", style), ("fillcolor", "gold")]);
+    });
+
+    ids.iter().for_each(|id| {
+        graph.0[*id].next.iter().filter(|next| id < next).for_each(|next| {
+            let steps: Vec<String> = route
+                .windows(2)
+                .enumerate()
+                .filter(|(_, w)| (&w[0].0, &w[1].0) == (*id, next) || (&w[0].0, &w[1].0) == (next, *id))
+                .map(|(step, _)| (step + 1).to_string())
+                .collect();
+            if steps.is_empty() {
+                dot.add_edge(id, next, &[]);
+            } else {
+                dot.add_edge(id, next, &[("label", &steps.join(",")), ("color", "red"), ("penwidth", "3")]);
+            }
+        });
+    });
+    dot
+}
+
+fn part1(data: &str) -> usize {
+    let graph: Graph = data
+        .lines()
+        .map(|line| line.parse::<Node>().unwrap())
+        .collect();
+
+    let mut cache = Cache::new();
+    let res = find_path(
+        &graph,
+        "AA",
+        &graph.init_rates(),
+        BitSet::new(),
+        30,
+        &mut cache,
+        &1,
+    );
+
+    if cli::verbose() {
+        eprintln!("Cache:: {}", cache.stats());
+    }
+    res
+}
+
+fn part2(data: &str) -> usize {
+    let graph: Graph = data
+        .lines()
+        .map(|line| line.parse::<Node>().unwrap())
+        .collect();
+
+    let mut cache = Cache::new();
+    let res = find_path(
+        &graph,
+        "AA",
+        &graph.init_rates(),
+        BitSet::new(),
+        26,
+        &mut cache,
+        &2,
+    );
+
+    if cli::verbose() {
+        eprintln!("Cache:: {}", cache.stats());
+    }
+    res
+}
+
+fn main() -> io::Result<()> {
+    println!();
+    println!(
+        "---( AOC{} - Day {} )-----------------------[Rust]----",
+        years::DEFAULT_YEAR, DAY
+    );
+
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day16.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
+
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day16.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
+    println!("--------------------------------------------------------");
+
+    if let Some(path) = cli::value("--dot") {
+        valves_graph(&input_data).save(path)?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, fmt::Debug, io, str::FromStr, string::ParseError};
 
-const YEAR: &'static str = "2022";
+use aoc::{bench, cli, input, years};
+
 const DAY: &'static str = "16";
 
 type Graph = HashMap<String, Node>;
@@ -184,17 +185,24 @@ fn main() -> io::Result<()> {
     println!();
     println!(
         "---( AOC{} - Day {} )-----------------------[Rust]----",
-        YEAR, DAY
+        years::DEFAULT_YEAR, DAY
     );
 
-    let test_data = include_str!("day16.test");
-    println!("Test :: Part 1 ====>     {}", part1(&test_data));
-    println!("Test :: Part 2 ====>     {}", part2(&test_data));
-    println!("--------------------------------------------------------");
+    let custom_input = cli::input()?;
+    if custom_input.is_none() {
+        let test_data = input!("day16.test");
+        println!("Test :: Part 1 ====>     {}", part1(&test_data));
+        println!("Test :: Part 2 ====>     {}", part2(&test_data));
+        println!("--------------------------------------------------------");
+    }
 
-    let input_data = include_str!("day16.in");
-    println!("Input:: Part 1 ====>     {}", part1(&input_data));
-    println!("Input:: Part 2 ====>     {}", part2(&input_data));
+    let input_data = custom_input.as_deref().unwrap_or_else(|| input!("day16.in"));
+    if cli::part(1) {
+        println!("Input:: Part 1 ====>     {}", bench::time(1, || part1(&input_data)));
+    }
+    if cli::part(2) {
+        println!("Input:: Part 2 ====>     {}", bench::time(2, || part2(&input_data)));
+    }
     println!("--------------------------------------------------------");
 
     Ok(())
@@ -0,0 +1,562 @@
+use std::{
+    env, fs,
+    io::{self, Error, ErrorKind},
+    path::PathBuf,
+    process,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc::{
+    bench::{self, Sample},
+    checksum::{self, Manifest},
+    cli, exec,
+    render::{Align, Color, Table},
+    report::{self, DayReport, Figure, Solution, Visual},
+    serve::{self, Limits, Request, Response, Server},
+    shrink::{Failure, Shrinker},
+    years::{self, Visualization, Year},
+};
+
+/// Local benchmark history, relative to the crate root.
+const BENCH_HISTORY: &str = "bench.history";
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Every command takes --year YYYY, the puzzles of 2022 by default.
+
+Commands:
+    bench        time each part on the real inputs and append it to bench.history
+                 --days LIST        days to run, e.g. 1,2,10 (default: all)
+                 --runs N           runs per day, the fastest one is kept (default: 3)
+                 --compare          flag parts slower than their best recorded time
+                 --threshold PCT    slowdown flagged by --compare (default: 10)
+                 --timeout SECS     run time before a day is stopped and skipped (default: 60)
+    shrink NN    minimize an input on which dayNN fails
+                 --input PATH       input to shrink (default: inputs/YYYY/dayNN.in)
+                 --against BIN      variant to compare answers with, e.g. day16_not_correct
+                 --failure KIND     panic, error, timeout or mismatch (default: the one observed)
+                 --timeout SECS     run time counted as a timeout (default: 10)
+                 --output PATH      reproducer (default: out/BIN.min.in)
+    repl NN      explore the model of dayNN interactively (2022: 05, 07, 09 and 11)
+                 test               use the example instead of the input
+                 --input PATH       input to load (default: inputs/YYYY/dayNN.in)
+                 --knots N          rope length for day 09 (default: 10)
+    crane NN M   top crates of dayNN with crane model M: 9000, 9001, batch:K or reversed:K (2022: 05)
+                 --input PATH       input to run (default: inputs/YYYY/dayNN.in)
+                 --lenient          skip the invalid moves instead of stopping
+    top NN K     the K best elves of dayNN with statistics of all of them (2022: 01)
+                 --input PATH       input to report on (default: inputs/YYYY/dayNN.in)
+    dot NN PATH  write the graph of dayNN to PATH in Graphviz DOT (2022: 07, 11 and 16)
+                 --input PATH       input to draw (default: inputs/YYYY/dayNN.in)
+    report       write out/report-YYYY.md and out/report-YYYY.html from runs of every day
+                 --days LIST        days to include, e.g. 1,2,10 (default: all)
+                 --timeout SECS     run time before a day is stopped (default: 600)
+    serve        answer `POST [/year/{y}]/day/{n}/part/{p}` with the input as body, in JSON
+                 --port N           port to listen on, on localhost (default: 2022)
+                 --max-body BYTES   largest input accepted (default: 1048576)
+                 --connections N    requests answered at once, the others get a 503 (default: 16)
+                 --timeout SECS     run time before a day is stopped (default: 60)
+    verify       check the inputs against inputs.sum
+                 --update           rewrite inputs.sum from the current inputs
+";
+
+fn usage_error(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidInput, format!("{}\n\n{}", msg, USAGE))
+}
+
+fn day_arg(args: &[String]) -> io::Result<u32> {
+    args.get(2)
+        .and_then(|day| day.parse::<u32>().ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| usage_error("Missing or invalid day number"))
+}
+
+fn shrink(year: &Year, day: u32) -> io::Result<()> {
+    let name = year.bin(day);
+    let input_path = cli::value("--input")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| year.input(env!("CARGO_MANIFEST_DIR"), day));
+    let output_path = cli::value("--output")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}/out/{}.min.in", env!("CARGO_MANIFEST_DIR"), name)));
+    let timeout = cli::value("--timeout")
+        .map(|secs| secs.parse::<f64>().map_err(|_| usage_error("Invalid --timeout")))
+        .transpose()?
+        .unwrap_or(10.0);
+
+    let mut shrinker =
+        Shrinker::new(exec::binary(&name)?, Duration::from_secs_f64(timeout)).by_blocks(year.block_days.contains(&day));
+    if let Some(against) = cli::value("--against") {
+        shrinker = shrinker.against(exec::binary(&against)?);
+    }
+
+    let input = fs::read_to_string(&input_path)?;
+    let observed = shrinker
+        .classify(&input)?
+        .ok_or_else(|| Error::other(format!("{} does not fail on {}", name, input_path.display())))?;
+    let target = match cli::value("--failure").as_deref() {
+        None => observed,
+        Some("panic") if matches!(observed, Failure::Panic(_)) => observed,
+        Some("error") if matches!(observed, Failure::Error(_)) => observed,
+        Some("timeout") if observed == Failure::Timeout => observed,
+        Some("mismatch") if observed == Failure::Mismatch => observed,
+        Some(kind @ ("panic" | "error" | "timeout" | "mismatch")) => {
+            return Err(Error::other(format!("Expected a {} failure, got {}", kind, observed)))
+        }
+        Some(_) => return Err(usage_error("Invalid --failure")),
+    };
+    eprintln!("Shrink:: {} {} on {} lines", name, target, input.lines().count());
+
+    let reproducer = shrinker.shrink(&input, &target);
+    fs::write(&output_path, &reproducer)?;
+    println!(
+        "Shrink:: {} -> {} lines ({} runs), written to {}",
+        input.lines().count(),
+        reproducer.lines().count(),
+        shrinker.runs(),
+        output_path.display()
+    );
+    Ok(())
+}
+
+/// Hands the terminal over to `dayNN --repl`.
+fn repl(year: &Year, day: u32, args: &[String]) -> io::Result<()> {
+    if !year.repl_days.contains(&day) {
+        return Err(usage_error(&format!("Day {:02} of {} has no REPL", day, year.year)));
+    }
+
+    let name = year.bin(day);
+    let mut command = process::Command::new(exec::binary(&name)?);
+    match args.first().map(|arg| arg.as_str()) {
+        Some("test") => command.arg("--repl").arg("test").args(&args[1..]),
+        _ => command.arg("--repl").args(args),
+    };
+    let status = command.status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::other(format!("{} {}", name, status)))
+    }
+}
+
+/// Runs `dayNN FLAG VALUE`, passing `--input` and the `switches` given to
+/// `aoc` along.
+fn run_with(year: &Year, day: u32, flag: &str, value: &str, switches: &[&str]) -> io::Result<exec::Run> {
+    let name = year.bin(day);
+    let mut args = vec![flag, value];
+    let input = cli::value("--input");
+    if let Some(input) = &input {
+        args.extend(["--input", input.as_str()]);
+    }
+    args.extend(switches.iter().filter(|switch| cli::flag(switch)));
+    let run = exec::run(&exec::binary(&name)?, &args, None, None)?;
+    if run.status != exec::Status::Success {
+        return Err(Error::other(format!("{} {} failed: {:?}\n{}", name, flag, run.status, run.stderr.trim_end())));
+    }
+    Ok(run)
+}
+
+/// Runs `dayNN --crane MODEL`, passing `--input` and `--lenient` along.
+fn crane(year: &Year, day: u32, args: &[String]) -> io::Result<()> {
+    if !year.crane_days.contains(&day) {
+        return Err(usage_error(&format!("Day {:02} of {} has no crane models", day, year.year)));
+    }
+    let model = args.first().ok_or_else(|| usage_error("Missing crane model"))?;
+
+    run_with(year, day, "--crane", model, &["--lenient"])?
+        .stdout
+        .lines()
+        .filter(|line| line.starts_with("Crane::"))
+        .for_each(|line| println!("{}", line));
+    Ok(())
+}
+
+/// Runs `dayNN --top K`, passing `--input` along.
+fn top(year: &Year, day: u32, args: &[String]) -> io::Result<()> {
+    if !year.top_days.contains(&day) {
+        return Err(usage_error(&format!("Day {:02} of {} has no top report", day, year.year)));
+    }
+    let k = args
+        .first()
+        .filter(|k| k.parse::<usize>().is_ok())
+        .ok_or_else(|| usage_error("Missing or invalid K"))?;
+
+    // The report follows the answers and their closing rule.
+    let run = run_with(year, day, "--top", k, &[])?;
+    let report = run.stdout.rsplit_once("-------\n").map_or(run.stdout.as_str(), |(_, report)| report);
+    print!("{}", report);
+    Ok(())
+}
+
+/// Runs `dayNN --dot PATH`, passing `--input` along.
+fn dot(year: &Year, day: u32, args: &[String]) -> io::Result<()> {
+    if !year.dot_days.contains(&day) {
+        return Err(usage_error(&format!("Day {:02} of {} has no DOT export", day, year.year)));
+    }
+    let path = args.first().ok_or_else(|| usage_error("Missing output path"))?;
+
+    run_with(year, day, "--dot", path, &[])?;
+    println!("Dot:: {} graph written to {}", year.bin(day), path);
+    Ok(())
+}
+
+/// Runs dayNN on the body of `POST [/year/{y}]/day/{n}/part/{p}`, of the
+/// year given to `serve` when the path has none.
+fn solve(request: &Request, default_year: &Year, timeout: Duration) -> Response {
+    let Some(route) = serve::solver_route(&request.path) else {
+        return Response::error(404, "Unknown route, use POST [/year/{y}]/day/{n}/part/{p}");
+    };
+    let year = match route.year {
+        None => default_year,
+        Some(year) => match years::get(year) {
+            Some(year) => year,
+            None => return Response::error(404, &format!("Unknown year {}", year)),
+        },
+    };
+    let (day, part, name) = (route.day, route.part, year.bin(route.day));
+    if request.method != "POST" {
+        return Response::error(405, "Use POST with the input as body");
+    }
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return Response::error(400, "Input is not UTF-8");
+    };
+    let Ok(bin) = exec::binary(&name) else {
+        return Response::error(404, &format!("Day {:02} of {} is not solved", day, year.year));
+    };
+
+    let part_arg = part.to_string();
+    let run = match exec::run(&bin, &["--input", "-", "--time", "--part", &part_arg], Some(input), Some(timeout)) {
+        Ok(run) => run,
+        Err(e) => return Response::error(500, &format!("Failed to run {}: {}", name, e)),
+    };
+    match run.status {
+        exec::Status::TimedOut => {
+            return Response::error(504, &format!("{} did not answer within {:.0?}", name, timeout))
+        }
+        exec::Status::Failed(_) => {
+            let location = run.panic_location().map_or(String::new(), |location| format!(" at {}", location));
+            return Response::error(422, &format!("{} failed on this input{}", name, location));
+        }
+        exec::Status::Success => {}
+    }
+
+    // Only the requested part is answered and timed.
+    let answers = report::answers(&run.stdout);
+    let Some(answer) = answers.first() else {
+        return Response::error(500, &format!("{} printed no answer for part {}", name, part));
+    };
+    if answer == "NOT IMPLEMENTED!" {
+        return Response::error(501, &format!("Day {:02} part {} of {} is not solved", day, part, year.year));
+    }
+    let time = bench::part_times(&run.stderr)
+        .first()
+        .map_or("null".to_owned(), |time| time.as_nanos().to_string());
+    Response::json(
+        200,
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"time_ns\":{},\"elapsed_ns\":{}}}",
+            year.year,
+            day,
+            part,
+            serve::json_string(answer),
+            time,
+            run.elapsed.as_nanos()
+        ),
+    )
+}
+
+/// Value of `flag` parsed as a `T`, `default` when the flag is missing.
+fn number<T: FromStr>(flag: &str, default: T) -> io::Result<T> {
+    cli::value(flag)
+        .map(|value| value.parse::<T>().map_err(|_| usage_error(&format!("Invalid {}: {}", flag, value))))
+        .transpose()
+        .map(|value| value.unwrap_or(default))
+}
+
+fn serve(year: &'static Year) -> io::Result<()> {
+    let port = number("--port", 2022u16)?;
+    let limits = Limits {
+        max_body: number("--max-body", Limits::default().max_body)?,
+        max_connections: number("--connections", Limits::default().max_connections)?,
+        ..Limits::default()
+    };
+    let timeout =
+        Duration::try_from_secs_f64(number("--timeout", 60.0)?).map_err(|_| usage_error("Invalid --timeout"))?;
+
+    let server = Server::bind(("127.0.0.1", port), limits)?;
+    eprintln!("Serve:: listening on http://{}", server.local_addr()?);
+    server.run(move |request| {
+        let response = solve(request, year, timeout);
+        eprintln!("Serve:: {} {} -> {}", request.method, request.path, response.status);
+        response
+    })
+}
+
+fn verify() -> io::Result<()> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let manifest_path = root.join(checksum::MANIFEST);
+    let actual = Manifest::scan(&root)?;
+
+    if cli::flag("--update") {
+        actual.save(&manifest_path)?;
+        println!("Verify:: {} inputs written to {}", actual.len(), manifest_path.display());
+        println!("Verify:: rebuild the days to embed the new checksums");
+        return Ok(());
+    }
+
+    let changes = Manifest::load(&manifest_path)?.diff(&actual);
+    changes.iter().for_each(|change| println!("{}", change));
+    if changes.is_empty() {
+        println!("Verify:: {} inputs match {}", actual.len(), checksum::MANIFEST);
+        Ok(())
+    } else {
+        Err(Error::other(format!(
+            "{} input(s) differ from {}, restore them or run `aoc verify --update`",
+            changes.len(),
+            checksum::MANIFEST
+        )))
+    }
+}
+
+/// Day binaries of the year's folder: the solution first, then its variants.
+fn day_bins(year: &Year, day: u32) -> io::Result<Vec<String>> {
+    let prefix = year.bin(day);
+    let mut bins: Vec<String> = fs::read_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(year.src_dir))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.strip_suffix(".rs").map(|bin| bin.to_owned()))
+        .filter(|bin| bin == &prefix || bin.starts_with(&format!("{}_", prefix)))
+        .collect();
+    bins.sort_by_key(|bin| (bin != &prefix, bin.clone()));
+    Ok(bins)
+}
+
+fn figure(bin: &str, visualization: &Visualization, timeout: Duration) -> io::Result<Figure> {
+    let (flag, ext) = (visualization.flag, visualization.ext);
+    let path = env::temp_dir().join(format!("aoc-report-{}.{}", bin, ext));
+    let run = exec::run(&exec::binary(bin)?, &[flag, &path.to_string_lossy()], None, Some(timeout))?;
+    if run.status != exec::Status::Success {
+        return Err(Error::other(format!("{} {} failed: {:?}", bin, flag, run.status)));
+    }
+    let visual = match ext {
+        "svg" => Visual::Svg(fs::read_to_string(&path)?),
+        _ => Visual::Png(fs::read(&path)?),
+    };
+    fs::remove_file(&path)?;
+    Ok(Figure {
+        caption: visualization.caption.to_owned(),
+        visual,
+    })
+}
+
+/// Days given with `--days 1,2,10`, all of them by default.
+fn days_arg() -> io::Result<Vec<u32>> {
+    match cli::value("--days") {
+        Some(list) => list
+            .split(',')
+            .map(|day| day.trim().parse::<u32>().map_err(|_| usage_error("Invalid --days")))
+            .collect(),
+        None => Ok((1..=25).collect()),
+    }
+}
+
+fn report(year: &Year) -> io::Result<()> {
+    let timeout = Duration::from_secs_f64(
+        cli::value("--timeout")
+            .map(|secs| secs.parse::<f64>().map_err(|_| usage_error("Invalid --timeout")))
+            .transpose()?
+            .unwrap_or(600.0),
+    );
+    let days = days_arg()?;
+
+    let mut reports = vec![];
+    for day in days {
+        let bins = day_bins(year, day)?;
+        let Some(main_bin) = bins.first() else {
+            continue;
+        };
+
+        let mut solutions = vec![];
+        for bin in bins.iter() {
+            eprintln!("Report:: running {}", bin);
+            let run = exec::run(&exec::binary(bin)?, &[], None, Some(timeout))?;
+            solutions.push(Solution::from_run(bin, &run));
+        }
+        let variants = solutions.split_off(1);
+
+        let mut figures = vec![];
+        for visualization in year.visualizations.iter().filter(|visualization| visualization.day == day) {
+            eprintln!("Report:: drawing {} {}", main_bin, visualization.flag);
+            match figure(main_bin, visualization, timeout) {
+                Ok(figure) => figures.push(figure),
+                Err(e) => eprintln!("Report:: skipping {} {}: {}", main_bin, visualization.flag, e),
+            }
+        }
+
+        reports.push(DayReport {
+            day,
+            title: year.title(day).to_owned(),
+            solution: solutions.remove(0),
+            variants,
+            figures,
+        });
+    }
+
+    let out_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/out"));
+    let (name, title) = (format!("report-{}", year.year), year.year.to_string());
+    let src_dir = format!("../{}", year.src_dir);
+    fs::write(out_dir.join(format!("{}.md", name)), report::markdown(&title, &reports, &src_dir))?;
+    fs::write(out_dir.join(format!("{}.html", name)), report::html(&title, &reports, &src_dir))?;
+    println!("Report:: {} days written to {}/{}.{{md,html}}", reports.len(), out_dir.display(), name);
+    Ok(())
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{:.3?}", time)
+}
+
+fn bench(year: &Year) -> io::Result<()> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let runs = cli::value("--runs")
+        .map(|runs| runs.parse::<usize>().map_err(|_| usage_error("Invalid --runs")))
+        .transpose()?
+        .unwrap_or(3)
+        .max(1);
+    let threshold = cli::value("--threshold")
+        .map(|pct| pct.parse::<f64>().map_err(|_| usage_error("Invalid --threshold")))
+        .transpose()?
+        .unwrap_or(10.0);
+    let timeout = Duration::from_secs_f64(
+        cli::value("--timeout")
+            .map(|secs| secs.parse::<f64>().map_err(|_| usage_error("Invalid --timeout")))
+            .transpose()?
+            .unwrap_or(60.0),
+    );
+
+    let (commit, date) = (bench::git_commit(&root), bench::now());
+    let mut samples: Vec<Sample> = vec![];
+    let mut timed_out: Vec<String> = vec![];
+    for day in days_arg()? {
+        let name = year.bin(day);
+        let input = year.input(&root, day);
+        if !day_bins(year, day)?.contains(&name) || !input.exists() {
+            continue;
+        }
+
+        let bin = exec::binary(&name)?;
+        let mut best: Vec<Duration> = vec![];
+        for run_idx in 0..runs {
+            eprintln!("Bench:: {} run {}/{}", name, run_idx + 1, runs);
+            let run = exec::run(&bin, &["--input", &input.to_string_lossy(), "--time"], None, Some(timeout))?;
+            if run.status == exec::Status::TimedOut {
+                best.clear();
+                timed_out.push(name.clone());
+                break;
+            }
+            if run.status != exec::Status::Success {
+                return Err(Error::other(format!("{} failed: {:?}\n{}", name, run.status, run.stderr)));
+            }
+            let times = bench::part_times(&run.stderr);
+            best = if best.is_empty() {
+                times
+            } else {
+                best.iter().zip(times).map(|(a, b)| (*a).min(b)).collect()
+            };
+        }
+
+        samples.extend(best.into_iter().enumerate().map(|(part, time)| Sample {
+            commit: commit.clone(),
+            date: date.clone(),
+            year: year.year,
+            day,
+            part: part as u32 + 1,
+            time,
+        }));
+    }
+
+    let history_path = root.join(BENCH_HISTORY);
+    let history = bench::load_history(&history_path)?;
+    let comparisons = bench::compare(&samples, &history);
+
+    let compare = cli::flag("--compare");
+    let mut table = Table::new(&format!("Bench {} at {} ({})", year.year, &commit[..commit.len().min(10)], date))
+        .header(
+            ["Day", "Part", "Time", "Best", "Best commit", "Change"]
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+        )
+        .align(Align::Right)
+        .align_column(4, Align::Left);
+    let mut regressions = 0;
+    for (row, comparison) in comparisons.iter().enumerate() {
+        let (best, best_commit) = match &comparison.best {
+            Some((time, commit)) => (format_time(*time), commit[..commit.len().min(10)].to_owned()),
+            None => ("-".to_owned(), "-".to_owned()),
+        };
+        table.push_row(vec![
+            format!("{:02}", comparison.day),
+            comparison.part.to_string(),
+            format_time(comparison.time),
+            best,
+            best_commit,
+            comparison.change().map_or("new".to_owned(), |change| format!("{:+.1}%", 100.0 * change)),
+        ]);
+        if compare && comparison.is_regression(threshold / 100.0) {
+            regressions += 1;
+            table = table.highlight(row, 5, Color::Red);
+        } else if compare && comparison.change().is_some_and(|change| change < 0.0) {
+            table = table.highlight(row, 5, Color::Green);
+        }
+    }
+    println!("{}", table);
+    timed_out
+        .iter()
+        .for_each(|name| println!("Bench:: {} skipped, no answer within {:.0?}", name, timeout));
+
+    bench::append_history(&history_path, &samples)?;
+    println!("Bench:: {} samples appended to {}", samples.len(), BENCH_HISTORY);
+
+    if regressions > 0 {
+        return Err(Error::other(format!(
+            "{} part(s) more than {}% slower than their best time",
+            regressions, threshold
+        )));
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let year = match years::from_cli() {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Error: {}", usage_error(&e));
+            process::exit(1);
+        }
+    };
+
+    let res = match args.get(1).map(|cmd| cmd.as_str()) {
+        Some("shrink") => day_arg(&args).and_then(|day| shrink(year, day)),
+        Some("repl") => day_arg(&args).and_then(|day| repl(year, day, &args[3..])),
+        Some("crane") => day_arg(&args).and_then(|day| crane(year, day, &args[3..])),
+        Some("top") => day_arg(&args).and_then(|day| top(year, day, &args[3..])),
+        Some("dot") => day_arg(&args).and_then(|day| dot(year, day, &args[3..])),
+        Some("bench") => bench(year),
+        Some("report") => report(year),
+        Some("serve") => serve(year),
+        Some("verify") => verify(),
+        Some("-h" | "--help") => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(usage_error("Missing or unknown command")),
+    };
+
+    if let Err(e) = res {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,343 @@
+//! Compact set of small unsigned integers.
+//!
+//! Items below 128 live in a single `u128`; the set switches to a growable
+//! vector of `u64` words only when a larger item is inserted, and switches
+//! back once those items are gone.
+
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::{BitAnd, BitOr, BitXor, Sub},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+const SMALL_BITS: usize = u128::BITS as usize;
+
+#[derive(Clone)]
+enum Words {
+    Small(u128),
+    Large(Vec<u64>),
+}
+
+#[derive(Clone)]
+pub struct BitSet(Words);
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet(Words::Small(0))
+    }
+
+    fn from_words<I: IntoIterator<Item = u64>>(words: I) -> Self {
+        let mut words: Vec<u64> = words.into_iter().collect();
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+
+        if words.len() <= SMALL_BITS / WORD_BITS {
+            let low = words.first().copied().unwrap_or(0) as u128;
+            let high = words.get(1).copied().unwrap_or(0) as u128;
+            BitSet(Words::Small(low | (high << WORD_BITS)))
+        } else {
+            BitSet(Words::Large(words))
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        match &self.0 {
+            Words::Small(_) => SMALL_BITS / WORD_BITS,
+            Words::Large(words) => words.len(),
+        }
+    }
+
+    fn word(&self, idx: usize) -> u64 {
+        match &self.0 {
+            Words::Small(bits) if idx == 0 => *bits as u64,
+            Words::Small(bits) if idx == 1 => (*bits >> WORD_BITS) as u64,
+            Words::Small(_) => 0,
+            Words::Large(words) => words.get(idx).copied().unwrap_or(0),
+        }
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let count = self.word_count().max(other.word_count());
+        Self::from_words((0..count).map(|idx| op(self.word(idx), other.word(idx))))
+    }
+
+    pub fn contains(&self, item: usize) -> bool {
+        self.word(item / WORD_BITS) & (1 << (item % WORD_BITS)) != 0
+    }
+
+    /// Returns `true` if the item was not already present.
+    pub fn insert(&mut self, item: usize) -> bool {
+        if self.contains(item) {
+            return false;
+        }
+
+        if let Words::Small(bits) = &mut self.0 {
+            if item < SMALL_BITS {
+                *bits |= 1 << item;
+                return true;
+            }
+            let bits = *bits;
+            self.0 = Words::Large(vec![bits as u64, (bits >> WORD_BITS) as u64]);
+        }
+
+        if let Words::Large(words) = &mut self.0 {
+            if words.len() <= item / WORD_BITS {
+                words.resize(item / WORD_BITS + 1, 0);
+            }
+            words[item / WORD_BITS] |= 1 << (item % WORD_BITS);
+        }
+        true
+    }
+
+    /// Returns `true` if the item was present.
+    pub fn remove(&mut self, item: usize) -> bool {
+        if !self.contains(item) {
+            return false;
+        }
+
+        match &mut self.0 {
+            Words::Small(bits) => *bits &= !(1 << item),
+            Words::Large(words) => {
+                words[item / WORD_BITS] &= !(1 << (item % WORD_BITS));
+                *self = Self::from_words(std::mem::take(words));
+            }
+        }
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Words::Small(bits) => bits.count_ones() as usize,
+            Words::Large(words) => words.iter().map(|w| w.count_ones() as usize).sum(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = Words::Small(0);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Items in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            set: self,
+            word_idx: 0,
+            word: self.word(0),
+        }
+    }
+}
+
+impl Default for BitSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Equality and hashing go through the trimmed words so that the same items
+// compare equal whichever representation holds them.
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        let count = self.word_count().max(other.word_count());
+        (0..count).all(|idx| self.word(idx) == other.word(idx))
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut count = self.word_count();
+        while count > 0 && self.word(count - 1) == 0 {
+            count -= 1;
+        }
+        (0..count).for_each(|idx| self.word(idx).hash(state));
+    }
+}
+
+impl Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| {
+            self.insert(item);
+        });
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: &BitSet) -> BitSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: &BitSet) -> BitSet {
+        self.intersection(other)
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        self.symmetric_difference(other)
+    }
+}
+
+impl Sub for &BitSet {
+    type Output = BitSet;
+
+    fn sub(self, other: &BitSet) -> BitSet {
+        self.difference(other)
+    }
+}
+
+pub struct Iter<'a> {
+    set: &'a BitSet,
+    word_idx: usize,
+    word: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.word_idx += 1;
+            if self.word_idx >= self.set.word_count() {
+                return None;
+            }
+            self.word = self.set.word(self.word_idx);
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.word_idx * WORD_BITS + bit)
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop::{self, Rng};
+    use std::collections::{hash_map::DefaultHasher, BTreeSet};
+
+    fn hash(set: &BitSet) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        set.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn insert_across_the_small_boundary() {
+        let mut set = BitSet::new();
+        assert!(set.insert(127));
+        assert!(matches!(set.0, Words::Small(_)));
+        assert!(set.insert(128));
+        assert!(matches!(set.0, Words::Large(_)));
+        assert!(!set.insert(128));
+
+        assert!(set.contains(127) && set.contains(128));
+        assert!(!set.contains(126) && !set.contains(129));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![127, 128]);
+
+        assert!(set.remove(128));
+        assert!(matches!(set.0, Words::Small(_)));
+        assert!(set.contains(127) && !set.contains(128));
+    }
+
+    #[test]
+    fn same_items_hash_alike() {
+        let small: BitSet = [3, 64, 127].into_iter().collect();
+        assert!(matches!(small.0, Words::Small(_)));
+        // Trailing zero words, as left behind by an in-place update.
+        let large = BitSet(Words::Large(vec![1 << 3, 1 << 63 | 1, 0, 0]));
+
+        assert_eq!(small, large);
+        assert_eq!(hash(&small), hash(&large));
+        assert_eq!(hash(&BitSet::new()), hash(&BitSet(Words::Large(vec![0; 3]))));
+    }
+
+    fn items(rng: &mut Rng) -> Vec<usize> {
+        // Mostly around the boundary, sometimes well past it.
+        rng.vec(12, |rng| match rng.below(4) {
+            0 => rng.below(400) as usize,
+            _ => 120 + rng.below(16) as usize,
+        })
+    }
+
+    #[test]
+    fn ops_agree_with_btreeset() {
+        prop::check(
+            "&, | and iter() agree with BTreeSet",
+            |rng| (items(rng), items(rng)),
+            |(a, b)| {
+                let (set_a, set_b): (BitSet, BitSet) = (a.iter().copied().collect(), b.iter().copied().collect());
+                let (ref_a, ref_b): (BTreeSet<usize>, BTreeSet<usize>) =
+                    (a.iter().copied().collect(), b.iter().copied().collect());
+                set_a.iter().eq(ref_a.iter().copied())
+                    && (&set_a & &set_b).iter().eq(ref_a.intersection(&ref_b).copied())
+                    && (&set_a | &set_b).iter().eq(ref_a.union(&ref_b).copied())
+                    && (&set_a & &set_b).len() == ref_a.intersection(&ref_b).count()
+            },
+        );
+    }
+}
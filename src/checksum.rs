@@ -0,0 +1,276 @@
+//! Checksums of the committed puzzle inputs.
+//!
+//! `inputs.sum` lists a 64-bit FNV-1a hash for every `.in` and `.test` file
+//! of `inputs/<year>`, in the `<hash>  <path>` layout of `sha256sum`. The manifest
+//! is embedded in the day binaries, which warn when the input they were built
+//! with no longer matches it; `aoc verify` checks the files on disk.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+    sync::Mutex,
+};
+
+/// Manifest location, relative to the crate root.
+pub const MANIFEST: &str = "inputs.sum";
+
+/// Folder holding the inputs, one sub-folder per year, relative to the crate
+/// root.
+pub const INPUTS_DIR: &str = "inputs";
+
+const EMBEDDED: &str = include_str!("../inputs.sum");
+
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Modified { path: String, expected: u64, actual: u64 },
+    Missing(String),
+    /// Input that is not in the manifest yet.
+    Unlisted(String),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Modified { path, expected, actual } => {
+                write!(f, "MODIFIED  {} (expected {:016x}, got {:016x})", path, expected, actual)
+            }
+            Change::Missing(path) => write!(f, "MISSING   {}", path),
+            Change::Unlisted(path) => write!(f, "UNLISTED  {}", path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<String, u64>,
+}
+
+fn is_input(name: &str) -> bool {
+    name.ends_with(".in") || name.rsplit_once('.').is_some_and(|(_, ext)| ext.starts_with("test"))
+}
+
+impl Manifest {
+    /// Manifest the binaries were built with.
+    pub fn embedded() -> Self {
+        EMBEDDED.parse().expect("Invalid embedded inputs.sum")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Hashes every input under `root`/[`INPUTS_DIR`]/`<year>`.
+    pub fn scan<P: AsRef<Path>>(root: P) -> io::Result<Self> {
+        let mut entries = BTreeMap::new();
+        for year in fs::read_dir(root.as_ref().join(INPUTS_DIR))? {
+            let year = year?;
+            if !year.file_type()?.is_dir() {
+                continue;
+            }
+            let year_name = year.file_name().to_string_lossy().into_owned();
+            for entry in fs::read_dir(year.path())? {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if entry.file_type()?.is_file() && is_input(&name) {
+                    let path = format!("{}/{}/{}", INPUTS_DIR, year_name, name);
+                    entries.insert(path, fnv1a(&fs::read(entry.path())?));
+                }
+            }
+        }
+        Ok(Manifest { entries })
+    }
+
+    pub fn get(&self, path: &str) -> Option<u64> {
+        self.entries.get(path).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// What changed from `self` to `actual`.
+    pub fn diff(&self, actual: &Manifest) -> Vec<Change> {
+        let mut changes: Vec<Change> = self
+            .entries
+            .iter()
+            .filter_map(|(path, expected)| match actual.get(path) {
+                None => Some(Change::Missing(path.clone())),
+                Some(hash) if hash != *expected => Some(Change::Modified {
+                    path: path.clone(),
+                    expected: *expected,
+                    actual: hash,
+                }),
+                Some(_) => None,
+            })
+            .collect();
+        changes.extend(
+            actual
+                .entries
+                .keys()
+                .filter(|path| !self.entries.contains_key(*path))
+                .map(|path| Change::Unlisted(path.clone())),
+        );
+        changes
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (hash, path) = line
+                    .split_once("  ")
+                    .ok_or_else(|| format!("Invalid manifest line: {}", line))?;
+                let hash = u64::from_str_radix(hash, 16).map_err(|e| format!("Invalid hash ({}): {}", hash, e))?;
+                Ok((path.trim().to_owned(), hash))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Manifest { entries })
+    }
+}
+
+impl Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# FNV-1a 64 of the puzzle inputs, update with `aoc verify --update`")?;
+        self.entries
+            .iter()
+            .try_for_each(|(path, hash)| writeln!(f, "{:016x}  {}", hash, path))
+    }
+}
+
+static WARNED: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+/// Warns on stderr, once per file, when `data` does not match the manifest
+/// entry of `inputs/<year>/<name>`.
+pub fn check(year: u32, name: &str, data: &str) {
+    let path = format!("{}/{}/{}", INPUTS_DIR, year, name);
+    let actual = fnv1a(data.as_bytes());
+    let warning = match Manifest::embedded().get(&path) {
+        Some(expected) if expected == actual => return,
+        Some(expected) => format!(
+            "{} does not match {} (expected {:016x}, got {:016x}), answers may differ from out/",
+            path, MANIFEST, expected, actual
+        ),
+        None => format!("{} is not listed in {}", path, MANIFEST),
+    };
+
+    let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+    if warned.get_or_insert_with(HashSet::new).insert(path) {
+        eprintln!("Warning:: {}, run `aoc verify`", warning);
+    }
+}
+
+/// `include_str!` of an input of `inputs/<year>`, checked against the
+/// manifest; [`DEFAULT_YEAR`](crate::years::DEFAULT_YEAR) when the year is
+/// left out.
+///
+/// ```text
+/// let input_data = aoc::input!("day01.in");
+/// let input_data = aoc::input!(2022, "day01.in");
+/// ```
+#[macro_export]
+macro_rules! input {
+    ($name:literal) => {{
+        let data: &'static str =
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/", $crate::default_year!(), "/", $name));
+        $crate::checksum::check($crate::years::DEFAULT_YEAR, $name, data);
+        data
+    }};
+    ($year:literal, $name:literal) => {{
+        let data: &'static str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/", $year, "/", $name));
+        $crate::checksum::check($year, $name, data);
+        data
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(entries: &[(&str, u64)]) -> Manifest {
+        Manifest {
+            entries: entries.iter().map(|(path, hash)| (path.to_string(), *hash)).collect(),
+        }
+    }
+
+    #[test]
+    fn fnv1a_known_answers() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn diffs_added_removed_and_changed() {
+        let expected = manifest(&[
+            ("inputs/2022/day01.in", 1),
+            ("inputs/2022/day02.in", 2),
+            ("inputs/2022/day03.in", 3),
+        ]);
+        let actual = manifest(&[
+            ("inputs/2022/day01.in", 1),
+            ("inputs/2022/day03.in", 4),
+            ("inputs/2022/day04.in", 5),
+        ]);
+        assert_eq!(
+            expected.diff(&actual),
+            vec![
+                Change::Missing("inputs/2022/day02.in".to_owned()),
+                Change::Modified {
+                    path: "inputs/2022/day03.in".to_owned(),
+                    expected: 3,
+                    actual: 4
+                },
+                Change::Unlisted("inputs/2022/day04.in".to_owned()),
+            ]
+        );
+        assert!(actual.diff(&actual).is_empty());
+    }
+
+    #[test]
+    fn parse_save_round_trip() {
+        let original = manifest(&[("inputs/2022/day01.in", 0x0123_4567_89ab_cdef), ("inputs/2022/day01.test", 42)]);
+        let text = original.to_string();
+        assert!(text.contains("0123456789abcdef  inputs/2022/day01.in\n"));
+        assert_eq!(text.parse::<Manifest>(), Ok(original.clone()));
+
+        let path = std::env::temp_dir().join(format!("aoc-manifest-{}.sum", std::process::id()));
+        original.save(&path).unwrap();
+        let loaded = Manifest::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, original);
+
+        assert!("xyz  inputs/2022/day01.in".parse::<Manifest>().is_err());
+        assert!("0123 inputs/2022/day01.in".parse::<Manifest>().is_err());
+        assert!(!Manifest::embedded().is_empty());
+    }
+
+    #[test]
+    fn inputs_by_name() {
+        assert!(is_input("day01.in") && is_input("day01.test") && is_input("day09.test2"));
+        assert!(!is_input("day01.out") && !is_input("README"));
+    }
+}
@@ -0,0 +1,51 @@
+//! Command line flags shared by the day binaries.
+
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+};
+
+pub fn flag(name: &str) -> bool {
+    env::args().skip(1).any(|arg| arg == name)
+}
+
+/// Value following `name`, as in `--image out.png`.
+pub fn value(name: &str) -> Option<String> {
+    env::args()
+        .skip(1)
+        .skip_while(|arg| arg != name)
+        .nth(1)
+}
+
+/// Whether to answer part `n`: both parts unless `--part N` picks one.
+pub fn part(n: u32) -> bool {
+    value("--part").is_none_or(|part| part == n.to_string())
+}
+
+pub fn verbose() -> bool {
+    flag("-v") || flag("--verbose")
+}
+
+/// Contents of the file given with `--input PATH`, or of stdin for `--input -`.
+pub fn input() -> io::Result<Option<String>> {
+    match value("--input").as_deref() {
+        None => Ok(None),
+        Some("-") => {
+            let mut data = String::new();
+            io::stdin().read_to_string(&mut data)?;
+            Ok(Some(data))
+        }
+        Some(path) => fs::read_to_string(path).map(Some),
+    }
+}
+
+/// Reader over the file given with `--input PATH`, or over stdin for
+/// `--input -`, for days that stream their input instead of loading it.
+pub fn input_reader() -> io::Result<Option<Box<dyn BufRead>>> {
+    match value("--input").as_deref() {
+        None => Ok(None),
+        Some("-") => Ok(Some(Box::new(BufReader::new(io::stdin())))),
+        Some(path) => Ok(Some(Box::new(BufReader::new(File::open(path)?)))),
+    }
+}
@@ -0,0 +1,123 @@
+//! Graphviz DOT export.
+//!
+//! ```text
+//! let dot = Dot::digraph("tree")
+//!     .node("/", &[("label", "/\n48381165"), ("shape", "box")])
+//!     .edge("/", "/a", &[]);
+//! dot.save("tree.dot")?;
+//! ```
+//!
+//! Render with `dot -Tsvg tree.dot -o tree.svg`.
+
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+type Attrs = Vec<(String, String)>;
+
+pub struct Dot {
+    name: String,
+    directed: bool,
+    graph_attrs: Attrs,
+    node_attrs: Attrs,
+    nodes: Vec<(String, Attrs)>,
+    edges: Vec<(String, String, Attrs)>,
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+fn to_attrs(attrs: &[(&str, &str)]) -> Attrs {
+    attrs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn format_attrs(attrs: &Attrs) -> String {
+    if attrs.is_empty() {
+        return String::new();
+    }
+    let attrs: Vec<String> = attrs.iter().map(|(k, v)| format!("{}={}", k, quote(v))).collect();
+    format!(" [{}]", attrs.join(", "))
+}
+
+impl Dot {
+    pub fn digraph(name: &str) -> Self {
+        Dot {
+            name: name.to_owned(),
+            directed: true,
+            graph_attrs: vec![],
+            node_attrs: vec![],
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+
+    /// Undirected graph, edges drawn without arrows.
+    pub fn graph(name: &str) -> Self {
+        Dot {
+            directed: false,
+            ..Self::digraph(name)
+        }
+    }
+
+    pub fn attr(mut self, key: &str, value: &str) -> Self {
+        self.graph_attrs.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Default attributes of every node.
+    pub fn node_attr(mut self, key: &str, value: &str) -> Self {
+        self.node_attrs.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn node(mut self, id: &str, attrs: &[(&str, &str)]) -> Self {
+        self.add_node(id, attrs);
+        self
+    }
+
+    pub fn edge(mut self, from: &str, to: &str, attrs: &[(&str, &str)]) -> Self {
+        self.add_edge(from, to, attrs);
+        self
+    }
+
+    pub fn add_node(&mut self, id: &str, attrs: &[(&str, &str)]) {
+        self.nodes.push((id.to_owned(), to_attrs(attrs)));
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str, attrs: &[(&str, &str)]) {
+        self.edges.push((from.to_owned(), to.to_owned(), to_attrs(attrs)));
+    }
+
+    pub fn render(&self) -> String {
+        let (kind, arrow) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+
+        let mut res = format!("{} {} {{\n", kind, quote(&self.name));
+        self.graph_attrs
+            .iter()
+            .for_each(|(k, v)| res += &format!("    {}={};\n", k, quote(v)));
+        if !self.node_attrs.is_empty() {
+            res += &format!("    node{};\n", format_attrs(&self.node_attrs));
+        }
+        self.nodes
+            .iter()
+            .for_each(|(id, attrs)| res += &format!("    {}{};\n", quote(id), format_attrs(attrs)));
+        self.edges.iter().for_each(|(from, to, attrs)| {
+            res += &format!("    {} {} {}{};\n", quote(from), arrow, quote(to), format_attrs(attrs))
+        });
+        res += "}\n";
+        res
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.render())
+    }
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
@@ -0,0 +1,125 @@
+//! Running the day binaries as child processes.
+//!
+//! Tools drive the days through their command line (`--input -` feeds the
+//! puzzle input on stdin) so that a panic or a runaway solver only takes the
+//! child down.
+
+use std::{
+    env,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Success,
+    /// Exit code, `None` when killed by a signal.
+    Failed(Option<i32>),
+    TimedOut,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub status: Status,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+}
+
+impl Run {
+    /// The `Input:: Part N ====> answer` lines.
+    pub fn answers(&self) -> Vec<String> {
+        self.stdout
+            .lines()
+            .filter(|line| line.starts_with("Input::"))
+            .map(|line| line.to_owned())
+            .collect()
+    }
+
+    /// `file:line:column` of the panic, if the binary panicked.
+    pub fn panic_location(&self) -> Option<String> {
+        self.stderr
+            .lines()
+            .find_map(|line| line.split_once("panicked at "))
+            .map(|(_, location)| location.trim_end_matches(':').to_owned())
+    }
+}
+
+/// Path of a binary built next to the current one, e.g. `day05`.
+pub fn binary(name: &str) -> io::Result<PathBuf> {
+    let path = env::current_exe()?.with_file_name(format!("{}{}", name, env::consts::EXE_SUFFIX));
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} not found, build it first with `cargo build --bins`", path.display()),
+        ))
+    }
+}
+
+fn drain<R: Read + Send + 'static>(reader: Option<R>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut res = Vec::new();
+        if let Some(mut reader) = reader {
+            let _ = reader.read_to_end(&mut res);
+        }
+        String::from_utf8_lossy(&res).into_owned()
+    })
+}
+
+fn wait(child: &mut Child, timeout: Option<Duration>, start: Instant) -> io::Result<Status> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(if status.success() {
+                Status::Success
+            } else {
+                Status::Failed(status.code())
+            });
+        }
+        if timeout.is_some_and(|timeout| start.elapsed() > timeout) {
+            child.kill()?;
+            child.wait()?;
+            return Ok(Status::TimedOut);
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+}
+
+/// Runs `bin` with `args`, writing `stdin` to it and killing it after
+/// `timeout`.
+pub fn run(bin: &Path, args: &[&str], stdin: Option<&str>, timeout: Option<Duration>) -> io::Result<Run> {
+    let start = Instant::now();
+    let mut child = Command::new(bin)
+        .args(args)
+        .stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let writer = child.stdin.take().map(|mut pipe| {
+        let data = stdin.unwrap_or_default().to_owned();
+        // A child that stops reading early closes the pipe, which is fine.
+        thread::spawn(move || {
+            let _ = pipe.write_all(data.as_bytes());
+        })
+    });
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let status = wait(&mut child, timeout, start)?;
+    let elapsed = start.elapsed();
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+
+    Ok(Run {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        elapsed,
+    })
+}
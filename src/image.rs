@@ -0,0 +1,218 @@
+//! RGB images written as PPM or PNG without any dependency.
+//!
+//! The PNG encoder only emits stored (uncompressed) deflate blocks, which
+//! every decoder accepts; the files are bigger but the code stays tiny.
+
+use std::{fs, io, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(238, 200, 60);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+
+    pub fn gray(level: u8) -> Rgb {
+        Rgb(level, level, level)
+    }
+
+    /// Linear blend from `self` (at 0) to `other` (at `max`).
+    pub fn blend(self, other: Rgb, value: usize, max: usize) -> Rgb {
+        let mix = |a: u8, b: u8| -> u8 {
+            match max {
+                0 => a,
+                _ => (a as isize + (b as isize - a as isize) * value.min(max) as isize / max as isize) as u8,
+            }
+        };
+        Rgb(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Out of bounds pixels are ignored.
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Nearest-neighbour upscaling, each pixel becoming a `factor`² square.
+    pub fn scale(&self, factor: usize) -> Image {
+        Image::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.get(x / factor, y / factor)
+        })
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut res = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        self.pixels.iter().for_each(|Rgb(r, g, b)| res.extend([r, g, b]));
+        res
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, truecolour, deflate, adaptive filtering, no interlace.
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Every scanline starts with its filter type, 0 being "none".
+        let raw: Vec<u8> = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|line| {
+                std::iter::once(0).chain(line.iter().flat_map(|Rgb(r, g, b)| [*r, *g, *b]))
+            })
+            .collect();
+
+        let mut res = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut res, b"IHDR", &header);
+        png_chunk(&mut res, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut res, b"IEND", &[]);
+        res
+    }
+
+    /// Writes a PNG, or a PPM when the path ends in `.ppm`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            _ => self.to_png(),
+        };
+        fs::write(path, bytes)
+    }
+}
+
+fn png_chunk(res: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    res.extend((data.len() as u32).to_be_bytes());
+    res.extend(kind);
+    res.extend(data);
+    res.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary, fastest compression.
+    let mut res = vec![0x78, 0x01];
+
+    let blocks: Vec<&[u8]> = data.chunks(u16::MAX as usize).collect();
+    if blocks.is_empty() {
+        res.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    blocks.iter().enumerate().for_each(|(idx, block)| {
+        let last = idx + 1 == blocks.len();
+        let len = block.len() as u16;
+        res.push(last as u8);
+        res.extend(len.to_le_bytes());
+        res.extend((!len).to_le_bytes());
+        res.extend(*block);
+    });
+
+    res.extend(adler32(data).to_be_bytes());
+    res
+}
+
+fn crc32<'a, I: IntoIterator<Item = &'a u8>>(bytes: I) -> u32 {
+    !bytes.into_iter().fold(!0_u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1_u32, 0_u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn stored_deflate_blocks() {
+        assert_eq!(zlib_stored(&[]), vec![0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]);
+
+        let data = vec![7; 70_000];
+        let zlib = zlib_stored(&data);
+        // Header, two blocks of 5 + data bytes, Adler-32.
+        assert_eq!(zlib.len(), 2 + 5 + 65_535 + 5 + 4_465 + 4);
+        assert_eq!(zlib[2..7], [0, 0xff, 0xff, 0, 0]);
+        assert_eq!(zlib[2 + 5 + 65_535..2 + 5 + 65_535 + 5], [1, 0x71, 0x11, 0x8e, 0xee]);
+        assert_eq!(zlib[zlib.len() - 4..], adler32(&data).to_be_bytes());
+    }
+
+    #[test]
+    fn png_layout() {
+        let png = Image::new(2, 1, Rgb::RED).to_png();
+        assert_eq!(png[..8], [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+        assert_eq!(png[8..16], [0, 0, 0, 13, b'I', b'H', b'D', b'R']);
+        assert_eq!(png[16..29], [0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        assert_eq!(png[png.len() - 12..], [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]);
+    }
+
+    #[test]
+    fn blends() {
+        assert_eq!(Rgb::BLACK.blend(Rgb::WHITE, 0, 4), Rgb::BLACK);
+        assert_eq!(Rgb::BLACK.blend(Rgb::WHITE, 2, 4), Rgb::gray(127));
+        assert_eq!(Rgb::WHITE.blend(Rgb::BLACK, 9, 4), Rgb::BLACK);
+        assert_eq!(Rgb::RED.blend(Rgb::BLUE, 3, 0), Rgb::RED);
+    }
+}
@@ -0,0 +1,17 @@
+pub mod animation;
+pub mod bench;
+pub mod bitset;
+pub mod checksum;
+pub mod cli;
+pub mod dot;
+pub mod exec;
+pub mod image;
+pub mod memo;
+pub mod prop;
+pub mod render;
+pub mod repl;
+pub mod report;
+pub mod serve;
+pub mod shrink;
+pub mod svg;
+pub mod years;
@@ -0,0 +1,179 @@
+//! Memoization cache for recursive solvers.
+//!
+//! Lookups and inserts are separate calls so a recursive function can check
+//! the cache, recurse with the same `&mut Memo`, then store its result.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+    pub entries: usize,
+}
+
+impl MemoStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entries, {} hits, {} misses ({:.1}% hit rate), {} evictions",
+            self.entries,
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.evictions
+        )
+    }
+}
+
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    // Insertion order, only tracked when a capacity is set.
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: MemoStats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            values: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Keeps at most `capacity` entries, evicting the oldest first.
+    pub fn with_capacity_limit(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        let Some(capacity) = self.capacity else {
+            self.values.insert(key, value);
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
+
+        if self.values.insert(key.clone(), value).is_none() {
+            self.order.push_back(key);
+            while self.values.len() > capacity {
+                let oldest = self.order.pop_front().expect("Order out of sync!");
+                self.values.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.order.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.values.len(),
+            ..self.stats
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_oldest_first_at_capacity() {
+        let mut memo = Memo::with_capacity_limit(2);
+        memo.insert(1, "one");
+        memo.insert(2, "two");
+        // Overwriting keeps the original insertion slot.
+        memo.insert(1, "uno");
+        memo.insert(3, "three");
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&1), None);
+        assert_eq!(memo.get(&2), Some("two"));
+        assert_eq!(memo.get(&3), Some("three"));
+
+        memo.insert(4, "four");
+        assert_eq!(memo.get(&2), None);
+        assert_eq!(memo.get(&4), Some("four"));
+        assert_eq!(memo.stats().evictions, 2);
+    }
+
+    #[test]
+    fn zero_capacity_stores_nothing() {
+        let mut memo = Memo::with_capacity_limit(0);
+        memo.insert(1, 1);
+        assert!(memo.is_empty());
+        assert_eq!(memo.get(&1), None);
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.get(&"a"), None);
+        memo.insert("a", 1);
+        assert_eq!(memo.get(&"a"), Some(1));
+        assert_eq!(memo.get(&"a"), Some(1));
+        assert_eq!(memo.get(&"b"), None);
+        memo.insert("b", 2);
+
+        let stats = memo.stats();
+        assert_eq!(
+            stats,
+            MemoStats {
+                hits: 2,
+                misses: 2,
+                evictions: 0,
+                entries: 2
+            }
+        );
+        assert_eq!(stats.hit_rate(), 0.5);
+        assert_eq!(MemoStats::default().hit_rate(), 0.0);
+    }
+}
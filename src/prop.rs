@@ -0,0 +1,263 @@
+//! Minimal property-based testing.
+//!
+//! ```text
+//! prop::check("sum is commutative", |rng| (rng.below(100), rng.below(100)), |(a, b)| a + b == b + a);
+//! ```
+//!
+//! Values are generated from a seeded [`Rng`], so a failure is reproducible:
+//! the panic message gives the seed, which can be replayed with
+//! `AOC_PROP_SEED=<seed> cargo test`. `AOC_PROP_CASES` changes the number of
+//! cases (256 by default). A failing value is shrunk through [`Shrink`] before
+//! being reported; a property that panics counts as failing.
+
+use std::{
+    env,
+    fmt::Debug,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+const DEFAULT_SEED: u64 = 20_221_225;
+const DEFAULT_CASES: usize = 256;
+
+/// SplitMix64 generator.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()).wrapping_add(1);
+        if span == 0 {
+            return self.next_u64() as i64;
+        }
+        range.start().wrapping_add(self.below(span) as i64)
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Up to `max_len` values made by `gen`.
+    pub fn vec<T>(&mut self, max_len: usize, mut gen: impl FnMut(&mut Rng) -> T) -> Vec<T> {
+        let len = self.below(max_len as u64 + 1) as usize;
+        (0..len).map(|_| gen(self)).collect()
+    }
+
+    /// Up to `max_len` characters taken from `alphabet`.
+    pub fn string(&mut self, max_len: usize, alphabet: &str) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        self.vec(max_len, |rng| *rng.pick(&chars)).into_iter().collect()
+    }
+}
+
+/// Smaller candidates for a failing value, most aggressive first.
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut res = vec![];
+                if *self > 0 {
+                    res.push(0);
+                }
+                if *self > 2 {
+                    res.push(*self / 2);
+                }
+                if *self > 1 {
+                    res.push(*self - 1);
+                }
+                res
+            }
+        }
+    )*};
+}
+
+macro_rules! shrink_signed {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut res = vec![];
+                if *self != 0 {
+                    res.push(0);
+                }
+                if *self < 0 && *self != <$t>::MIN {
+                    res.push(-*self);
+                }
+                if self.abs_diff(0) > 2 {
+                    res.push(*self / 2);
+                }
+                if self.abs_diff(0) > 1 {
+                    res.push(*self - self.signum());
+                }
+                res
+            }
+        }
+    )*};
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+shrink_signed!(i8, i16, i32, i64, isize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        vec!['a', '0'].into_iter().filter(|c| c < self).collect()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut res = vec![];
+        if self.is_empty() {
+            return res;
+        }
+        res.push(vec![]);
+        if self.len() > 1 {
+            let half = self.len() / 2;
+            res.push(self[..half].to_vec());
+            res.push(self[half..].to_vec());
+        }
+        if self.len() > 2 {
+            (0..self.len()).for_each(|idx| res.push([&self[..idx], &self[idx + 1..]].concat()));
+        }
+        self.iter().enumerate().for_each(|(idx, item)| {
+            item.shrink().into_iter().for_each(|smaller| {
+                let mut candidate = self.clone();
+                candidate[idx] = smaller;
+                res.push(candidate);
+            })
+        });
+        res
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        let chars: Vec<char> = self.chars().collect();
+        chars
+            .shrink()
+            .into_iter()
+            .map(|chars| chars.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let mut res: Vec<Self> = a.shrink().into_iter().map(|a| (a, b.clone())).collect();
+        res.extend(b.shrink().into_iter().map(|b| (a.clone(), b)));
+        res
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+        let mut res: Vec<Self> = a.shrink().into_iter().map(|a| (a, b.clone(), c.clone())).collect();
+        res.extend(b.shrink().into_iter().map(|b| (a.clone(), b, c.clone())));
+        res.extend(c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c)));
+        res
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone, D: Shrink + Clone> Shrink for (A, B, C, D) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c, d) = self;
+        let mut res: Vec<Self> = a
+            .shrink()
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone(), d.clone()))
+            .collect();
+        res.extend(b.shrink().into_iter().map(|b| (a.clone(), b, c.clone(), d.clone())));
+        res.extend(c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c, d.clone())));
+        res.extend(d.shrink().into_iter().map(|d| (a.clone(), b.clone(), c.clone(), d)));
+        res
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn holds<T>(prop: &impl Fn(&T) -> bool, value: &T) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(|| prop(value))).unwrap_or(false)
+}
+
+/// Upper bound on shrinking steps, in case a shrinker cycles.
+const MAX_SHRINKS: usize = 10_000;
+
+fn shrink_failure<T: Shrink>(value: T, prop: &impl Fn(&T) -> bool) -> (T, usize) {
+    let mut value = value;
+    let mut steps = 0;
+    while steps < MAX_SHRINKS {
+        match value.shrink().into_iter().find(|candidate| !holds(prop, candidate)) {
+            Some(smaller) => {
+                value = smaller;
+                steps += 1;
+            }
+            None => break,
+        }
+    }
+    (value, steps)
+}
+
+/// Checks `prop` against values made by `gen`, panicking with the shrunk
+/// counterexample when it fails.
+pub fn check<T, G, P>(name: &str, gen: G, prop: P)
+where
+    T: Shrink + Debug,
+    G: Fn(&mut Rng) -> T,
+    P: Fn(&T) -> bool,
+{
+    let seed = env_or("AOC_PROP_SEED", DEFAULT_SEED);
+    let cases = env_or("AOC_PROP_CASES", DEFAULT_CASES);
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let value = gen(&mut rng);
+        if holds(&prop, &value) {
+            continue;
+        }
+
+        let original = format!("{:?}", value);
+        let (shrunk, steps) = shrink_failure(value, &prop);
+        panic!(
+            "Property `{}` failed at case {} (AOC_PROP_SEED={})\n  original: {}\n  shrunk ({} steps): {:?}",
+            name, case, seed, original, steps, shrunk
+        );
+    }
+}
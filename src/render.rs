@@ -0,0 +1,291 @@
+//! Titled Unicode box rendering for tables and grids.
+//!
+//! ```text
+//! ┌─ Grid 3x2 ─┐
+//! │    0  1  2 │
+//! ├────────────┤
+//! │ 0  1  2  3 │
+//! │ 1  4  5 16 │
+//! └────────────┘
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Bold,
+}
+
+impl Color {
+    fn code(&self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+            Color::Bold => "1",
+        }
+    }
+
+    pub fn paint(&self, text: &str) -> String {
+        format!("\x1b[{}m{}\x1b[0m", self.code(), text)
+    }
+}
+
+pub struct Table {
+    title: String,
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    align: Align,
+    column_align: HashMap<usize, Align>,
+    row_indices: bool,
+    column_indices: bool,
+    highlights: HashMap<(usize, usize), Color>,
+    colored: bool,
+    spacing: usize,
+    uniform: bool,
+}
+
+impl Table {
+    pub fn new(title: &str) -> Self {
+        Table {
+            title: title.to_owned(),
+            header: None,
+            rows: vec![],
+            align: Align::Right,
+            column_align: HashMap::new(),
+            row_indices: false,
+            column_indices: false,
+            highlights: HashMap::new(),
+            colored: true,
+            spacing: 1,
+            uniform: false,
+        }
+    }
+
+    /// Table holding one cell per grid value, in row-major order, with every
+    /// column as wide as the widest value.
+    pub fn grid<T: Display>(title: &str, grid: &[Vec<T>]) -> Self {
+        let mut table = grid.iter().fold(Table::new(title), |table, row| {
+            table.row(row.iter().map(|value| value.to_string()).collect())
+        });
+        table.uniform = true;
+        table
+    }
+
+    pub fn header(mut self, header: Vec<String>) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn row(mut self, row: Vec<String>) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    pub fn push_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /// Default alignment of every column.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn align_column(mut self, column: usize, align: Align) -> Self {
+        self.column_align.insert(column, align);
+        self
+    }
+
+    pub fn with_row_indices(mut self) -> Self {
+        self.row_indices = true;
+        self
+    }
+
+    pub fn with_column_indices(mut self) -> Self {
+        self.column_indices = true;
+        self
+    }
+
+    /// Spaces between two columns.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn highlight(mut self, row: usize, column: usize, color: Color) -> Self {
+        self.highlights.insert((row, column), color);
+        self
+    }
+
+    pub fn highlight_all<I: IntoIterator<Item = (usize, usize)>>(mut self, cells: I, color: Color) -> Self {
+        cells.into_iter().for_each(|cell| {
+            self.highlights.insert(cell, color);
+        });
+        self
+    }
+
+    /// Turns ANSI colours off, e.g. when the output is not a terminal.
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    fn columns_len(&self) -> usize {
+        self.rows
+            .iter()
+            .chain(self.header.iter())
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn pad(text: &str, width: usize, align: Align) -> String {
+        let fill = width.saturating_sub(text.chars().count());
+        let (left, right) = match align {
+            Align::Left => (0, fill),
+            Align::Right => (fill, 0),
+            Align::Center => (fill / 2, fill - fill / 2),
+        };
+        format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+    }
+
+    /// Lines of the box content, and the position of the rule separating the
+    /// header lines from the rows, if there are any header lines.
+    fn content_lines(&self) -> (Vec<String>, Option<usize>) {
+        let columns_len = self.columns_len();
+        let index_width = self.rows.len().saturating_sub(1).to_string().len();
+
+        let widths: Vec<usize> = (0..columns_len)
+            .map(|c| {
+                self.rows
+                    .iter()
+                    .chain(self.header.iter())
+                    .filter_map(|row| row.get(c))
+                    .map(|cell| cell.chars().count())
+                    .chain(self.column_indices.then(|| c.to_string().len()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let widths: Vec<usize> = match widths.iter().max() {
+            Some(max) if self.uniform => vec![*max; widths.len()],
+            _ => widths,
+        };
+
+        let separator = " ".repeat(self.spacing);
+        let line = |index: Option<String>, cells: Vec<String>| -> String {
+            let mut parts: Vec<String> = vec![];
+            if self.row_indices {
+                parts.push(Self::pad(&index.unwrap_or_default(), index_width, Align::Right));
+            }
+            parts.extend(cells);
+            parts.join(&separator)
+        };
+
+        let mut lines: Vec<String> = vec![];
+        if self.column_indices {
+            lines.push(line(
+                None,
+                widths
+                    .iter()
+                    .enumerate()
+                    .map(|(c, width)| Self::pad(&c.to_string(), *width, self.align_of(c)))
+                    .collect(),
+            ));
+        }
+        if let Some(header) = &self.header {
+            lines.push(line(
+                None,
+                widths
+                    .iter()
+                    .enumerate()
+                    .map(|(c, width)| {
+                        let cell = header.get(c).map(|h| h.as_str()).unwrap_or("");
+                        Self::pad(cell, *width, self.align_of(c))
+                    })
+                    .collect(),
+            ));
+        }
+        let rule = (!lines.is_empty()).then_some(lines.len());
+
+        self.rows.iter().enumerate().for_each(|(r, row)| {
+            let cells = widths
+                .iter()
+                .enumerate()
+                .map(|(c, width)| {
+                    let cell = Self::pad(row.get(c).map(|v| v.as_str()).unwrap_or(""), *width, self.align_of(c));
+                    match self.highlights.get(&(r, c)) {
+                        Some(color) if self.colored => color.paint(&cell),
+                        _ => cell,
+                    }
+                })
+                .collect();
+            lines.push(line(Some(r.to_string()), cells));
+        });
+
+        (lines, rule)
+    }
+
+    fn align_of(&self, column: usize) -> Align {
+        *self.column_align.get(&column).unwrap_or(&self.align)
+    }
+
+    fn visible_width(line: &str) -> usize {
+        let mut width = 0;
+        let mut in_escape = false;
+        line.chars().for_each(|c| match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => width += 1,
+        });
+        width
+    }
+
+    pub fn render(&self) -> String {
+        let (lines, rule) = self.content_lines();
+
+        let title = format!(" {} ", self.title);
+        let content_width = lines.iter().map(|l| Self::visible_width(l)).max().unwrap_or(0);
+        // One space of margin on each side, and room for the title after "┌─".
+        let inner_width = (content_width + 2).max(title.chars().count() + 2);
+
+        let mut res = String::new();
+        res += &format!("┌─{}{}┐\n", title, "─".repeat(inner_width - 1 - title.chars().count()));
+        lines.iter().enumerate().for_each(|(idx, line)| {
+            if Some(idx) == rule {
+                res += &format!("├{}┤\n", "─".repeat(inner_width));
+            }
+            let fill = inner_width - 2 - Self::visible_width(line);
+            res += &format!("│ {}{} │\n", line, " ".repeat(fill));
+        });
+        res += &format!("└{}┘\n", "─".repeat(inner_width));
+        res
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
@@ -0,0 +1,219 @@
+//! Interactive exploration of a day's parsed model.
+//!
+//! A day implements [`Session`] over its model and starts [`run`] when given
+//! `--repl` (`aoc repl NN` does that for you). Commands:
+//!
+//! - `step [N]` apply the next N steps (1 by default)
+//! - `show` render the current state
+//! - `query ...` day-specific questions, see `help`
+//! - `reset` reload the model from the input
+//! - `history` list the commands entered, `!N` runs the Nth again, `!!` the last
+//! - `help`, `quit`
+
+use std::io::{self, BufRead, Write};
+
+pub trait Session {
+    /// Applies `n` steps and describes them, or explains why it can't.
+    fn step(&mut self, n: usize) -> Result<String, String>;
+
+    fn show(&self) -> String;
+
+    fn query(&self, args: &[&str]) -> Result<String, String>;
+
+    /// Usage lines of `query`.
+    fn query_help(&self) -> Vec<String>;
+}
+
+/// Steps described in full when stepping by a larger count.
+const SHOWN_STEPS: usize = 10;
+
+/// Keeps the descriptions of the last steps, counting the others.
+pub fn last_steps(steps: Vec<String>) -> Vec<String> {
+    if steps.len() <= SHOWN_STEPS {
+        return steps;
+    }
+    let skipped = steps.len() - SHOWN_STEPS;
+    let mut res = vec![format!("... {} more", skipped)];
+    res.extend(steps.into_iter().skip(skipped));
+    res
+}
+
+const HELP: &str = "\
+step [N]     apply the next N steps (default 1)
+show         render the current state
+query ...    ask the model, see below
+reset        reload the model from the input
+history      list the commands entered
+!N / !!      run the Nth / last command again
+help         this help
+quit         leave (or Ctrl-D)";
+
+/// Runs one command and returns what to print, `None` once asked to quit.
+fn execute<S: Session>(
+    session: &mut S,
+    init: &impl Fn() -> Result<S, String>,
+    history: &[String],
+    line: &str,
+) -> Option<Result<String, String>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let res = match words[..] {
+        ["quit" | "exit" | "q"] => return None,
+        ["help" | "?"] => {
+            let mut help = HELP.to_owned();
+            session
+                .query_help()
+                .iter()
+                .for_each(|usage| help += &format!("\n  query {}", usage));
+            Ok(help)
+        }
+        ["history"] => Ok(history
+            .iter()
+            .enumerate()
+            .map(|(idx, command)| format!("{:>4}  {}", idx + 1, command))
+            .collect::<Vec<String>>()
+            .join("\n")),
+        ["show"] => Ok(session.show()),
+        ["reset"] => init().map(|fresh| {
+            *session = fresh;
+            "Model reloaded".to_owned()
+        }),
+        ["step"] => session.step(1),
+        ["step", n] => n
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid step count: {}", n))
+            .and_then(|n| session.step(n)),
+        ["query", ref args @ ..] => session.query(args),
+        _ => Err(format!("Unknown command: {} (try `help`)", line)),
+    };
+    Some(res)
+}
+
+/// `!!` and `!N` are replaced by the command they refer to.
+fn expand(line: &str, history: &[String]) -> Result<String, String> {
+    match line.strip_prefix('!') {
+        None => Ok(line.to_owned()),
+        Some("!") => history.last().cloned().ok_or_else(|| "History is empty".to_owned()),
+        Some(n) => n
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| history.get(n.checked_sub(1)?))
+            .cloned()
+            .ok_or_else(|| format!("No command {} in history", n)),
+    }
+}
+
+/// Reads commands from stdin until `quit` or end of input; `init` builds the
+/// model, again on `reset`, and its error ends the REPL before it starts.
+pub fn run<S: Session>(name: &str, init: impl Fn() -> Result<S, String>) -> io::Result<()> {
+    let mut session = init().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut history: Vec<String> = vec![];
+    println!("{} REPL, `help` for the commands", name);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}> ", name);
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            println!();
+            return Ok(());
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let command = match expand(line, &history) {
+            Ok(command) => command,
+            Err(e) => {
+                println!("Error: {}", e);
+                continue;
+            }
+        };
+        if command != line {
+            println!("{}", command);
+        }
+        history.push(command.clone());
+        match execute(&mut session, &init, &history, &command) {
+            None => return Ok(()),
+            Some(Ok(out)) if out.is_empty() => {}
+            Some(Ok(out)) => println!("{}", out),
+            Some(Err(e)) => println!("Error: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts its steps, up to 3.
+    struct Counter(usize);
+
+    impl Session for Counter {
+        fn step(&mut self, n: usize) -> Result<String, String> {
+            if self.0 + n > 3 {
+                return Err("Only 3 steps".to_owned());
+            }
+            self.0 += n;
+            Ok(format!("at {}", self.0))
+        }
+
+        fn show(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn query(&self, _args: &[&str]) -> Result<String, String> {
+            Ok(String::new())
+        }
+
+        fn query_help(&self) -> Vec<String> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn expands_history() {
+        let history = vec!["step 2".to_owned(), "show".to_owned()];
+        assert_eq!(expand("step", &history), Ok("step".to_owned()));
+        assert_eq!(expand("!!", &history), Ok("show".to_owned()));
+        assert_eq!(expand("!1", &history), Ok("step 2".to_owned()));
+        assert_eq!(expand("!0", &history), Err("No command 0 in history".to_owned()));
+        assert_eq!(expand("!3", &history), Err("No command 3 in history".to_owned()));
+        assert_eq!(expand("!x", &history), Err("No command x in history".to_owned()));
+        assert_eq!(expand("!!", &[]), Err("History is empty".to_owned()));
+    }
+
+    #[test]
+    fn executes_commands() {
+        let init = || Ok(Counter(0));
+        let mut session = Counter(0);
+        assert_eq!(execute(&mut session, &init, &[], "step 2"), Some(Ok("at 2".to_owned())));
+        assert_eq!(execute(&mut session, &init, &[], "step 2"), Some(Err("Only 3 steps".to_owned())));
+        assert_eq!(execute(&mut session, &init, &[], "step two"), Some(Err("Invalid step count: two".to_owned())));
+        assert_eq!(
+            execute(&mut session, &init, &[], "jump 2"),
+            Some(Err("Unknown command: jump 2 (try `help`)".to_owned()))
+        );
+        assert_eq!(execute(&mut session, &init, &[], "show"), Some(Ok("2".to_owned())));
+        assert_eq!(execute(&mut session, &init, &[], "reset"), Some(Ok("Model reloaded".to_owned())));
+        assert_eq!(session.0, 0);
+        assert_eq!(execute(&mut session, &init, &[], "quit"), None);
+
+        let broken = || Err::<Counter, _>("bad input".to_owned());
+        session.0 = 1;
+        assert_eq!(execute(&mut session, &broken, &[], "reset"), Some(Err("bad input".to_owned())));
+        assert_eq!(session.0, 1);
+    }
+
+    #[test]
+    fn truncates_long_steps() {
+        let steps = |n: usize| (1..=n).map(|step| step.to_string()).collect::<Vec<String>>();
+        assert_eq!(last_steps(steps(SHOWN_STEPS)), steps(SHOWN_STEPS));
+
+        let shown = last_steps(steps(25));
+        assert_eq!(shown.len(), SHOWN_STEPS + 1);
+        assert_eq!(shown[0], "... 15 more");
+        assert_eq!(shown[1..], steps(25)[15..]);
+    }
+}
@@ -0,0 +1,325 @@
+//! Year summary as Markdown and as a standalone HTML page.
+//!
+//! The reports are built from runs of the day binaries (see [`crate::exec`]):
+//! answers are read back from the `Input:: Part N ====>` lines of their
+//! output, and visualizations are the files written by their `--image` and
+//! `--svg` flags, embedded in the HTML page.
+
+use std::{fmt::Write, time::Duration};
+
+use crate::exec::{Run, Status};
+
+/// Answer of a part, read from the output of a day.
+///
+/// Multi-line answers (day10's CRT) are printed under an empty
+/// `Input:: Part N ====>` line, up to the next separator.
+pub fn answers(stdout: &str) -> Vec<String> {
+    let lines: Vec<&str> = stdout.lines().collect();
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| line.strip_prefix("Input:: Part ").map(|part| (idx, part)))
+        .filter_map(|(idx, part)| part.split_once("====>").map(|(_, answer)| (idx, answer.trim())))
+        .map(|(idx, answer)| {
+            if !answer.is_empty() {
+                return answer.to_owned();
+            }
+            let block: Vec<&str> = lines[idx + 1..]
+                .iter()
+                .take_while(|line| !line.starts_with("Input::") && !line.starts_with("-----"))
+                .copied()
+                .collect();
+            block.join("\n").trim_matches('\n').to_owned()
+        })
+        .collect()
+}
+
+fn is_solved(answer: &str) -> bool {
+    !answer.is_empty() && answer != "NOT IMPLEMENTED!"
+}
+
+pub enum Visual {
+    Png(Vec<u8>),
+    Svg(String),
+}
+
+pub struct Figure {
+    pub caption: String,
+    pub visual: Visual,
+}
+
+/// One binary of a day, the solution itself or a variant of it.
+pub struct Solution {
+    pub bin: String,
+    pub status: Status,
+    pub answers: Vec<String>,
+    pub elapsed: Duration,
+}
+
+impl Solution {
+    pub fn from_run(bin: &str, run: &Run) -> Self {
+        Solution {
+            bin: bin.to_owned(),
+            status: run.status.clone(),
+            answers: answers(&run.stdout),
+            elapsed: run.elapsed,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        if self.status != Status::Success {
+            return 0;
+        }
+        self.answers.iter().take(2).filter(|answer| is_solved(answer)).count()
+    }
+
+    fn answer(&self, part: usize) -> &str {
+        self.answers.get(part).map(|answer| answer.as_str()).unwrap_or("")
+    }
+
+    fn state(&self) -> String {
+        match self.status {
+            Status::Success => format!("{:.2?}", self.elapsed),
+            Status::Failed(code) => format!("failed ({})", code.map_or("signal".to_owned(), |c| c.to_string())),
+            Status::TimedOut => format!("timed out after {:.0?}", self.elapsed),
+        }
+    }
+}
+
+pub struct DayReport {
+    pub day: u32,
+    pub title: String,
+    pub solution: Solution,
+    pub variants: Vec<Solution>,
+    pub figures: Vec<Figure>,
+}
+
+fn stars(count: usize) -> String {
+    format!("{}{}", "★".repeat(count), "☆".repeat(2 - count.min(2)))
+}
+
+fn total_stars(days: &[DayReport]) -> usize {
+    days.iter().map(|day| day.solution.stars()).sum()
+}
+
+/// Answer as a table cell, multi-line answers being shown after the table.
+fn cell(answer: &str) -> String {
+    if answer.contains('\n') {
+        "*(see below)*".to_owned()
+    } else if answer.is_empty() {
+        "-".to_owned()
+    } else {
+        format!("`{}`", answer)
+    }
+}
+
+/// `src_dir` is the folder of the solutions as seen from the report.
+pub fn markdown(year: &str, days: &[DayReport], src_dir: &str) -> String {
+    let mut res = format!("# Advent of Code {}\n\n", year);
+    let _ = writeln!(res, "{} / {} stars\n", total_stars(days), 2 * days.len());
+    res += "| Day | Title | Stars | Part 1 | Part 2 | Runtime | Variants |\n";
+    res += "|----:|-------|:-----:|--------|--------|--------:|----------|\n";
+
+    days.iter().for_each(|day| {
+        let variants: Vec<String> = day
+            .variants
+            .iter()
+            .map(|variant| {
+                let agrees = variant.answers == day.solution.answers;
+                format!(
+                    "[{0}]({1}/{0}.rs) {2}",
+                    variant.bin,
+                    src_dir,
+                    if agrees { "(same answers)" } else { "(different answers)" }
+                )
+            })
+            .collect();
+        let _ = writeln!(
+            res,
+            "| {:02} | [{}]({}/{}.rs) | {} | {} | {} | {} | {} |",
+            day.day,
+            day.title,
+            src_dir,
+            day.solution.bin,
+            stars(day.solution.stars()),
+            cell(day.solution.answer(0)),
+            cell(day.solution.answer(1)),
+            day.solution.state(),
+            variants.join("<br>")
+        );
+    });
+
+    days.iter().for_each(|day| {
+        day.solution
+            .answers
+            .iter()
+            .enumerate()
+            .filter(|(_, answer)| answer.contains('\n'))
+            .for_each(|(part, answer)| {
+                let _ = write!(
+                    res,
+                    "\n## Day {:02} - Part {}\n\n```text\n{}\n```\n",
+                    day.day,
+                    part + 1,
+                    answer
+                );
+            });
+    });
+
+    res += "\nRuntimes are the wall-clock time of the whole binary, examples included.\n";
+    res
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn base64(data: &[u8]) -> String {
+    data.chunks(3)
+        .flat_map(|chunk| {
+            let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+            let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+            (0..4).map(move |idx| {
+                if idx > chunk.len() {
+                    '='
+                } else {
+                    BASE64[(n >> (18 - 6 * idx) & 0x3f) as usize] as char
+                }
+            })
+        })
+        .collect()
+}
+
+const STYLE: &str = "\
+body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em auto; max-width: 1100px; }
+h1, h2 { color: #00cc00; }
+a { color: #009900; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #333340; padding: 0.3em 0.8em; text-align: left; vertical-align: top; }
+.stars { color: #ffff66; }
+.failed { color: #ff6666; }
+pre { background: #10101a; border: 1px solid #333340; padding: 0.5em; }
+figure { display: inline-block; margin: 0 1em 1em 0; }
+figure img, figure svg { max-width: 100%; height: auto; image-rendering: pixelated; background: #ffffff; }
+";
+
+fn html_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("<pre>{}</pre>", escape(answer))
+    } else if answer.is_empty() {
+        "-".to_owned()
+    } else {
+        format!("<code>{}</code>", escape(answer))
+    }
+}
+
+/// Standalone page, images and drawings being inlined.
+pub fn html(year: &str, days: &[DayReport], src_dir: &str) -> String {
+    let mut res = String::new();
+    res += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n";
+    let _ = writeln!(res, "<title>Advent of Code {}</title>", year);
+    let _ = writeln!(res, "<style>\n{}</style>\n</head>\n<body>", STYLE);
+    let _ = writeln!(res, "<h1>Advent of Code {}</h1>", year);
+    let _ = writeln!(
+        res,
+        "<p><span class=\"stars\">{} / {} stars</span></p>",
+        total_stars(days),
+        2 * days.len()
+    );
+
+    res += "<table>\n<tr><th>Day</th><th>Title</th><th>Stars</th><th>Part 1</th><th>Part 2</th><th>Runtime</th><th>Variants</th></tr>\n";
+    days.iter().for_each(|day| {
+        let solution = &day.solution;
+        let variants: Vec<String> = day
+            .variants
+            .iter()
+            .map(|variant| {
+                let (class, note) = if variant.answers == solution.answers {
+                    ("", "same answers")
+                } else {
+                    ("failed", "different answers")
+                };
+                format!(
+                    "<a href=\"{0}/{1}.rs\">{1}</a> <span class=\"{2}\">({3})</span>",
+                    src_dir, variant.bin, class, note
+                )
+            })
+            .collect();
+        let state_class = if solution.status == Status::Success { "" } else { "failed" };
+        let _ = writeln!(
+            res,
+            "<tr><td><a href=\"#day{0:02}\">{0:02}</a></td><td><a href=\"{1}/{2}.rs\">{3}</a></td><td class=\"stars\">{4}</td><td>{5}</td><td>{6}</td><td class=\"{7}\">{8}</td><td>{9}</td></tr>",
+            day.day,
+            src_dir,
+            solution.bin,
+            escape(&day.title),
+            stars(solution.stars()),
+            html_answer(solution.answer(0)),
+            html_answer(solution.answer(1)),
+            state_class,
+            escape(&solution.state()),
+            variants.join("<br>")
+        );
+    });
+    res += "</table>\n";
+
+    days.iter().filter(|day| !day.figures.is_empty()).for_each(|day| {
+        let _ = writeln!(res, "<h2 id=\"day{:02}\">Day {:02}: {}</h2>", day.day, day.day, escape(&day.title));
+        day.figures.iter().for_each(|figure| {
+            res += "<figure>\n";
+            // Inlined SVGs would share their ids (`#viewport`) within the page.
+            let (mime, data) = match &figure.visual {
+                Visual::Png(data) => ("image/png", data.as_slice()),
+                Visual::Svg(svg) => ("image/svg+xml", svg.as_bytes()),
+            };
+            let _ = writeln!(
+                res,
+                "<img alt=\"{}\" src=\"data:{};base64,{}\">",
+                escape(&figure.caption),
+                mime,
+                base64(data)
+            );
+            let _ = writeln!(res, "<figcaption>{}</figcaption>\n</figure>", escape(&figure.caption));
+        });
+    });
+
+    res += "<p>Runtimes are the wall-clock time of the whole binary, examples included.</p>\n";
+    res += "</body>\n</html>\n";
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_answers() {
+        let stdout = "\
+Test :: Part 1 ====>     13140
+--------------------------------------------------------
+Input:: Part 1 ====>     15260
+Input:: Part 2 ====>     
+
+###...##.
+#..#.#..#
+
+--------------------------------------------------------
+Crane:: not an answer
+";
+        assert_eq!(answers(stdout), vec!["15260".to_owned(), "###...##.\n#..#.#..#".to_owned()]);
+        assert_eq!(answers("Input:: Part 1 ====>     NOT IMPLEMENTED!\n"), vec!["NOT IMPLEMENTED!"]);
+        assert!(answers("Test :: Part 1 ====>     1\n").is_empty());
+    }
+
+    #[test]
+    fn base64_pads() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xfb, 0xff]), "+/8=");
+    }
+}
@@ -0,0 +1,472 @@
+//! Minimal HTTP/1.1 server, for `aoc serve` to expose the solvers as a JSON
+//! API.
+//!
+//! One thread per connection, one request per connection (`Connection: close`
+//! is always answered). Bodies need a `Content-Length`; chunked uploads are
+//! refused. [`Limits`] caps the size of the request, how long a client may
+//! take to send it and how many connections are served at once, the handler
+//! enforces its own time limit on the work.
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Request line and headers.
+    pub max_head: usize,
+    pub max_body: usize,
+    /// Time allowed for reading the request and writing the response.
+    pub io_timeout: Duration,
+    /// Connections served at once, the others are answered 503.
+    pub max_connections: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_head: 8 * 1024,
+            max_body: 1024 * 1024,
+            io_timeout: Duration::from_secs(10),
+            max_connections: 16,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Names are lowercased.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// JSON document.
+    pub body: String,
+}
+
+impl Response {
+    pub fn json(status: u16, body: String) -> Self {
+        Response { status, body }
+    }
+
+    /// `{"error": msg}`.
+    pub fn error(status: u16, msg: &str) -> Self {
+        Response::json(status, format!("{{\"error\":{}}}", json_string(msg)))
+    }
+
+    pub fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            422 => "Unprocessable Content",
+            431 => "Request Header Fields Too Large",
+            500 => "Internal Server Error",
+            501 => "Not Implemented",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "",
+        }
+    }
+}
+
+/// `s` as a JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    s.chars().for_each(|c| match c {
+        '"' => res += "\\\"",
+        '\\' => res += "\\\\",
+        '\n' => res += "\\n",
+        '\r' => res += "\\r",
+        '\t' => res += "\\t",
+        c if (c as u32) < 0x20 => res += &format!("\\u{:04x}", c as u32),
+        c => res.push(c),
+    });
+    res.push('"');
+    res
+}
+
+fn read_error(e: io::Error) -> Response {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => Response::error(408, "Request not received in time"),
+        _ => Response::error(400, &format!("Invalid request: {}", e)),
+    }
+}
+
+/// Line of the head, without its line ending, counted against `budget`.
+fn read_line<R: BufRead>(reader: &mut R, budget: &mut usize) -> Result<String, Response> {
+    let mut line = Vec::new();
+    reader
+        .by_ref()
+        .take(*budget as u64 + 1)
+        .read_until(b'\n', &mut line)
+        .map_err(read_error)?;
+    if line.len() > *budget {
+        return Err(Response::error(431, "Request head too large"));
+    }
+    if !line.ends_with(b"\n") {
+        return Err(Response::error(400, "Incomplete request"));
+    }
+    *budget -= line.len();
+    String::from_utf8(line)
+        .map(|line| line.trim_end_matches(['\r', '\n']).to_owned())
+        .map_err(|_| Response::error(400, "Request head is not UTF-8"))
+}
+
+/// Reads a request, or the response explaining why it is refused.
+pub fn read_request<R: BufRead>(reader: &mut R, limits: &Limits) -> Result<Request, Response> {
+    let mut budget = limits.max_head;
+    let request_line = read_line(reader, &mut budget)?;
+    let [method, path, version] = request_line.split(' ').collect::<Vec<&str>>()[..] else {
+        return Err(Response::error(400, "Invalid request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(Response::error(400, &format!("Unsupported version {}", version)));
+    }
+
+    let mut headers = vec![];
+    loop {
+        let line = read_line(reader, &mut budget)?;
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(Response::error(400, &format!("Invalid header: {}", line)));
+        };
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+    }
+
+    let mut request = Request {
+        method: method.to_owned(),
+        path: path.to_owned(),
+        headers,
+        body: vec![],
+    };
+    if request.header("transfer-encoding").is_some() {
+        return Err(Response::error(
+            501,
+            "Chunked bodies are not supported, send a Content-Length",
+        ));
+    }
+    let length = match request.header("content-length") {
+        Some(length) => length
+            .parse::<usize>()
+            .map_err(|_| Response::error(400, "Invalid Content-Length"))?,
+        None if request.method == "POST" => return Err(Response::error(411, "Content-Length required")),
+        None => 0,
+    };
+    if length > limits.max_body {
+        return Err(Response::error(
+            413,
+            &format!("Body of {} bytes, the limit is {}", length, limits.max_body),
+        ));
+    }
+
+    request.body = vec![0; length];
+    reader.read_exact(&mut request.body).map_err(read_error)?;
+    Ok(request)
+}
+
+pub fn write_response<W: Write>(writer: &mut W, response: &Response) -> io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    writer.flush()
+}
+
+/// One of the [`Limits::max_connections`], given back when dropped.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn take(active: &Arc<AtomicUsize>, max: usize) -> Option<Slot> {
+        active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| (count < max).then_some(count + 1))
+            .ok()
+            .map(|_| Slot(Arc::clone(active)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handle<H: Fn(&Request) -> Response>(stream: TcpStream, limits: &Limits, handler: &H, slot: Slot) -> io::Result<()> {
+    stream.set_read_timeout(Some(limits.io_timeout))?;
+    stream.set_write_timeout(Some(limits.io_timeout))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader, limits) {
+        Ok(request) => handler(&request),
+        Err(response) => response,
+    };
+    // Free before answering, so that the client may follow up right away.
+    drop(slot);
+    let mut stream = stream;
+    write_response(&mut stream, &response)
+}
+
+/// Answers 503 without reading the request, from the accepting thread.
+fn refuse(mut stream: TcpStream, limits: &Limits) -> io::Result<()> {
+    stream.set_write_timeout(Some(limits.io_timeout))?;
+    write_response(&mut stream, &Response::error(503, "Too many requests at once, retry later"))?;
+    stream.shutdown(Shutdown::Write)?;
+
+    // Closing on unread data would reset the connection before the client
+    // reads the answer.
+    stream.set_read_timeout(Some(Duration::from_millis(100)))?;
+    let _ = io::copy(&mut stream.take((limits.max_head + limits.max_body) as u64), &mut io::sink());
+    Ok(())
+}
+
+pub struct Server {
+    listener: TcpListener,
+    limits: Limits,
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(addr: A, limits: Limits) -> io::Result<Self> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            limits,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers requests with `handler` until the listener fails.
+    pub fn run<H>(self, handler: H) -> io::Result<()>
+    where
+        H: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        let handler = Arc::new(handler);
+        let active = Arc::new(AtomicUsize::new(0));
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let Some(slot) = Slot::take(&active, self.limits.max_connections) else {
+                if let Err(e) = refuse(stream, &self.limits) {
+                    eprintln!("Serve:: {}", e);
+                }
+                continue;
+            };
+            let (handler, limits) = (Arc::clone(&handler), self.limits);
+            thread::spawn(move || {
+                if let Err(e) = handle(stream, &limits, handler.as_ref(), slot) {
+                    eprintln!("Serve:: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverRoute {
+    /// `None` for the default year.
+    pub year: Option<u32>,
+    pub day: u32,
+    pub part: u32,
+}
+
+/// Puzzle of `/day/{n}/part/{p}` or `/year/{y}/day/{n}/part/{p}`.
+pub fn solver_route(path: &str) -> Option<SolverRoute> {
+    let path = path.split('?').next().unwrap_or(path);
+    let (year, day, part) = match path.trim_end_matches('/').split('/').collect::<Vec<&str>>()[..] {
+        ["", "day", day, "part", part] => (None, day, part),
+        ["", "year", year, "day", day, "part", part] => (Some(year.parse::<u32>().ok()?), day, part),
+        _ => return None,
+    };
+    Some(SolverRoute {
+        year,
+        day: day.parse::<u32>().ok().filter(|day| (1..=25).contains(day))?,
+        part: part.parse::<u32>().ok().filter(|part| (1..=2).contains(part))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(limits: Limits) -> SocketAddr {
+        let server = Server::bind("127.0.0.1:0", limits).unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || {
+            server.run(|request| match solver_route(&request.path) {
+                Some(route) if request.method == "POST" => Response::json(
+                    200,
+                    format!(
+                        "{{\"year\":{},\"day\":{},\"part\":{},\"echo\":{}}}",
+                        route.year.unwrap_or(0),
+                        route.day,
+                        route.part,
+                        json_string(&String::from_utf8_lossy(&request.body))
+                    ),
+                ),
+                Some(_) => Response::error(405, "Use POST"),
+                None => Response::error(404, "Not found"),
+            })
+        });
+        addr
+    }
+
+    /// Status line and body of the answer to `raw`.
+    fn send(addr: SocketAddr, raw: &[u8]) -> (String, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw).unwrap();
+        let mut answer = String::new();
+        stream.read_to_string(&mut answer).unwrap();
+        let (head, body) = answer.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_owned(), body.to_owned())
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> (String, String) {
+        let raw = format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        );
+        send(addr, raw.as_bytes())
+    }
+
+    #[test]
+    fn answers_post() {
+        let addr = start(Limits::default());
+        assert_eq!(
+            post(addr, "/day/1/part/2", "1000\n2000\n"),
+            (
+                "HTTP/1.1 200 OK".to_owned(),
+                "{\"year\":0,\"day\":1,\"part\":2,\"echo\":\"1000\\n2000\\n\"}".to_owned()
+            )
+        );
+        assert_eq!(
+            post(addr, "/year/2022/day/5/part/1", "").1,
+            "{\"year\":2022,\"day\":5,\"part\":1,\"echo\":\"\"}"
+        );
+    }
+
+    #[test]
+    fn routes() {
+        let addr = start(Limits::default());
+        assert_eq!(post(addr, "/day/26/part/1", "").0, "HTTP/1.1 404 Not Found");
+        assert_eq!(post(addr, "/day/1/part/3", "").0, "HTTP/1.1 404 Not Found");
+        assert_eq!(post(addr, "/year/x/day/1/part/1", "").0, "HTTP/1.1 404 Not Found");
+        let (status, _) = send(addr, b"GET /day/1/part/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, "HTTP/1.1 405 Method Not Allowed");
+    }
+
+    #[test]
+    fn refuses_large_bodies() {
+        let addr = start(Limits {
+            max_body: 16,
+            ..Limits::default()
+        });
+        assert_eq!(post(addr, "/day/1/part/1", &"1\n".repeat(8)).0, "HTTP/1.1 200 OK");
+        let (status, body) = post(addr, "/day/1/part/1", &"1\n".repeat(9));
+        assert_eq!(status, "HTTP/1.1 413 Content Too Large");
+        assert_eq!(body, "{\"error\":\"Body of 18 bytes, the limit is 16\"}");
+    }
+
+    #[test]
+    fn refuses_large_heads() {
+        let addr = start(Limits {
+            max_head: 64,
+            ..Limits::default()
+        });
+        let raw = format!("POST /day/1/part/1 HTTP/1.1\r\nX-Padding: {}\r\n\r\n", "x".repeat(64));
+        assert_eq!(
+            send(addr, raw.as_bytes()).0,
+            "HTTP/1.1 431 Request Header Fields Too Large"
+        );
+    }
+
+    #[test]
+    fn requires_length() {
+        let addr = start(Limits::default());
+        let (status, _) = send(addr, b"POST /day/1/part/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, "HTTP/1.1 411 Length Required");
+        let (status, _) = send(
+            addr,
+            b"POST /day/1/part/1 HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n",
+        );
+        assert_eq!(status, "HTTP/1.1 501 Not Implemented");
+    }
+
+    #[test]
+    fn times_out_slow_clients() {
+        let addr = start(Limits {
+            io_timeout: Duration::from_millis(100),
+            ..Limits::default()
+        });
+        // Announces a body that never comes.
+        let (status, _) = send(addr, b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\n1000");
+        assert_eq!(status, "HTTP/1.1 408 Request Timeout");
+    }
+
+    #[test]
+    fn refuses_beyond_max_connections() {
+        let server = Server::bind(
+            "127.0.0.1:0",
+            Limits {
+                max_connections: 1,
+                ..Limits::default()
+            },
+        )
+        .unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || {
+            server.run(|_| {
+                thread::sleep(Duration::from_millis(300));
+                Response::json(200, "{}".to_owned())
+            })
+        });
+
+        let busy = thread::spawn(move || post(addr, "/day/1/part/1", ""));
+        thread::sleep(Duration::from_millis(100));
+        let (status, body) = post(addr, "/day/1/part/1", "1000\n");
+        assert_eq!(status, "HTTP/1.1 503 Service Unavailable");
+        assert_eq!(body, "{\"error\":\"Too many requests at once, retry later\"}");
+
+        assert_eq!(busy.join().unwrap().0, "HTTP/1.1 200 OK");
+        assert_eq!(post(addr, "/day/1/part/1", "").0, "HTTP/1.1 200 OK");
+    }
+
+    #[test]
+    fn escapes_json() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+}
@@ -0,0 +1,240 @@
+//! Failing-input minimizer (delta debugging).
+//!
+//! [`ddmin`] is Zeller's algorithm: split the input in `n` chunks, keep a
+//! chunk or the complement of a chunk whenever it still fails, and refine the
+//! split when nothing can be dropped.
+//!
+//! [`Shrinker`] applies it to a day binary: the input is cut in blank-line
+//! separated blocks (or taken as a single block of lines), whole blocks are
+//! removed first, then lines within the remaining blocks.
+
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::exec::{self, Status};
+
+/// Smallest subset of `items` (in the 1-minimal sense) for which `fails`
+/// still holds. `fails(&items)` is assumed to be true.
+pub fn ddmin<T: Clone>(items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut items = items;
+    let mut n = 2;
+
+    while items.len() >= 2 {
+        let chunk_len = items.len().div_ceil(n);
+        let chunks: Vec<(usize, usize)> = (0..items.len())
+            .step_by(chunk_len)
+            .map(|start| (start, (start + chunk_len).min(items.len())))
+            .collect();
+
+        let subset = chunks
+            .iter()
+            .map(|(start, end)| items[*start..*end].to_vec())
+            .find(|subset| fails(subset));
+        if let Some(subset) = subset {
+            items = subset;
+            n = 2;
+            continue;
+        }
+
+        let complement = chunks
+            .iter()
+            .map(|(start, end)| [&items[..*start], &items[*end..]].concat())
+            .find(|complement| fails(complement));
+        if let Some(complement) = complement {
+            items = complement;
+            n = (n - 1).max(2);
+            continue;
+        }
+
+        if n >= items.len() {
+            break;
+        }
+        n = (2 * n).min(items.len());
+    }
+
+    items
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Panic, identified by its `file:line:column`.
+    Panic(String),
+    /// Failed without panicking, e.g. an error returned from `main`, with its
+    /// exit code (`None` when killed by a signal).
+    Error(Option<i32>),
+    Timeout,
+    /// Both variants run fine but give different answers.
+    Mismatch,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic(location) => write!(f, "panic at {}", location),
+            Failure::Error(Some(code)) => write!(f, "error (exit code {})", code),
+            Failure::Error(None) => write!(f, "error (killed by a signal)"),
+            Failure::Timeout => write!(f, "timeout"),
+            Failure::Mismatch => write!(f, "variant mismatch"),
+        }
+    }
+}
+
+type Blocks = Vec<Vec<String>>;
+
+fn split(input: &str, by_blocks: bool) -> Blocks {
+    if by_blocks {
+        input
+            .trim_end_matches('\n')
+            .split("\n\n")
+            .map(|block| block.lines().map(|line| line.to_owned()).collect())
+            .collect()
+    } else {
+        vec![input.lines().map(|line| line.to_owned()).collect()]
+    }
+}
+
+fn join(blocks: &Blocks) -> String {
+    let blocks: Vec<String> = blocks.iter().map(|lines| lines.join("\n")).collect();
+    blocks.join("\n\n") + "\n"
+}
+
+pub struct Shrinker {
+    bin: PathBuf,
+    against: Option<PathBuf>,
+    timeout: Duration,
+    by_blocks: bool,
+    runs: usize,
+}
+
+impl Shrinker {
+    pub fn new(bin: PathBuf, timeout: Duration) -> Self {
+        Shrinker {
+            bin,
+            against: None,
+            timeout,
+            by_blocks: false,
+            runs: 0,
+        }
+    }
+
+    /// Variant whose answers are compared with the ones of the main binary.
+    pub fn against(mut self, bin: PathBuf) -> Self {
+        self.against = Some(bin);
+        self
+    }
+
+    /// Removes blank-line separated blocks before single lines.
+    pub fn by_blocks(mut self, by_blocks: bool) -> Self {
+        self.by_blocks = by_blocks;
+        self
+    }
+
+    /// Child processes started so far.
+    pub fn runs(&self) -> usize {
+        self.runs
+    }
+
+    pub fn classify(&mut self, input: &str) -> io::Result<Option<Failure>> {
+        let mut bins = vec![self.bin.clone()];
+        bins.extend(self.against.clone());
+
+        let mut answers = vec![];
+        for bin in bins {
+            self.runs += 1;
+            let run = exec::run(&bin, &["--input", "-"], Some(input), Some(self.timeout))?;
+            match run.status {
+                Status::TimedOut => return Ok(Some(Failure::Timeout)),
+                Status::Failed(code) => {
+                    return Ok(Some(run.panic_location().map_or(Failure::Error(code), Failure::Panic)));
+                }
+                Status::Success => answers.push(run.answers()),
+            }
+        }
+
+        Ok(match answers.as_slice() {
+            [main, other] if main != other => Some(Failure::Mismatch),
+            _ => None,
+        })
+    }
+
+    fn fails(&mut self, blocks: &Blocks, target: &Failure) -> bool {
+        match self.classify(&join(blocks)) {
+            Ok(failure) => failure.as_ref() == Some(target),
+            Err(e) => {
+                eprintln!("Run failed: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Smallest input still showing `target`.
+    pub fn shrink(&mut self, input: &str, target: &Failure) -> String {
+        let blocks = split(input, self.by_blocks);
+
+        let blocks = if blocks.len() > 1 {
+            let blocks = ddmin(blocks, |candidate| self.fails(&candidate.to_vec(), target));
+            eprintln!("Shrink:: {} block(s) left after {} runs", blocks.len(), self.runs);
+            blocks
+        } else {
+            blocks
+        };
+
+        let blocks = (0..blocks.len()).fold(blocks, |blocks, idx| {
+            let lines = ddmin(blocks[idx].clone(), |candidate| {
+                let mut blocks = blocks.clone();
+                blocks[idx] = candidate.to_vec();
+                self.fails(&blocks, target)
+            });
+            eprintln!(
+                "Shrink:: block {} down to {} line(s) after {} runs",
+                idx + 1,
+                lines.len(),
+                self.runs
+            );
+
+            let mut blocks = blocks;
+            blocks[idx] = lines;
+            blocks
+        });
+
+        join(&blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddmin_is_one_minimal() {
+        let fails = |items: &[u32]| items.contains(&3) && items.contains(&7);
+        let mut calls = 0;
+        let min = ddmin((0..20).collect(), |items| {
+            calls += 1;
+            fails(items)
+        });
+        assert_eq!(min, vec![3, 7]);
+        assert!((0..min.len()).all(|idx| !fails(&[&min[..idx], &min[idx + 1..]].concat())));
+        assert!(calls < 100, "{} calls", calls);
+
+        assert_eq!(ddmin(vec![1, 2, 3], |items: &[u32]| !items.is_empty()), vec![1]);
+        assert_eq!(ddmin(vec![5], |_: &[u32]| true), vec![5]);
+    }
+
+    #[test]
+    fn split_join_round_trip() {
+        let input = "1000\n2000\n\n3000\n\n4000\n5000\n";
+        let blocks = split(input, true);
+        assert_eq!(blocks, vec![vec!["1000", "2000"], vec!["3000"], vec!["4000", "5000"]]);
+        assert_eq!(join(&blocks), input);
+
+        let lines = split(input, false);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), 7);
+        assert_eq!(join(&lines), input);
+    }
+}
@@ -0,0 +1,238 @@
+//! SVG drawings built as plain strings.
+//!
+//! Shapes are given in puzzle coordinates and mapped onto a canvas of at most
+//! `size` pixels, so coordinates in the millions stay readable. Shapes that
+//! fall entirely outside the [`Viewport`] are dropped, and the rest is
+//! clipped to it.
+
+use std::{fmt::Write, fs, io, path::Path, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Viewport {
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Viewport {
+            min_x: min_x.min(max_x),
+            min_y: min_y.min(max_y),
+            max_x: max_x.max(min_x),
+            max_y: max_y.max(min_y),
+        }
+    }
+
+    /// Smallest viewport holding all the points.
+    pub fn around<I: IntoIterator<Item = (f64, f64)>>(points: I) -> Self {
+        points.into_iter().fold(
+            Viewport {
+                min_x: f64::MAX,
+                min_y: f64::MAX,
+                max_x: f64::MIN,
+                max_y: f64::MIN,
+            },
+            |view, (x, y)| Viewport {
+                min_x: view.min_x.min(x),
+                min_y: view.min_y.min(y),
+                max_x: view.max_x.max(x),
+                max_y: view.max_y.max(y),
+            },
+        )
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    fn intersects(&self, other: &Viewport) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+/// Parses `min_x,min_y,max_x,max_y`.
+impl FromStr for Viewport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values: Vec<f64> = s
+            .split(',')
+            .map(|v| v.trim().parse::<f64>().map_err(|e| format!("Invalid viewport ({}): {}", s, e)))
+            .collect::<Result<_, _>>()?;
+
+        match values[..] {
+            [min_x, min_y, max_x, max_y] => Ok(Viewport::new(min_x, min_y, max_x, max_y)),
+            _ => Err(format!("Invalid viewport ({}): expected min_x,min_y,max_x,max_y", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Style {
+    fill: Option<String>,
+    stroke: Option<(String, f64)>,
+    opacity: Option<f64>,
+}
+
+impl Style {
+    pub fn fill(color: &str) -> Self {
+        Style {
+            fill: Some(color.to_owned()),
+            ..Style::default()
+        }
+    }
+
+    /// Outline only, `width` in pixels.
+    pub fn stroke(color: &str, width: f64) -> Self {
+        Style::default().with_stroke(color, width)
+    }
+
+    pub fn with_stroke(mut self, color: &str, width: f64) -> Self {
+        self.stroke = Some((color.to_owned(), width));
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    fn attrs(&self) -> String {
+        let mut res = format!(" fill=\"{}\"", self.fill.as_deref().unwrap_or("none"));
+        if let Some((color, width)) = &self.stroke {
+            let _ = write!(res, " stroke=\"{}\" stroke-width=\"{}\"", color, width);
+        }
+        if let Some(opacity) = self.opacity {
+            let _ = write!(res, " opacity=\"{}\"", opacity);
+        }
+        res
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+pub struct Svg {
+    viewport: Viewport,
+    scale: f64,
+    elements: Vec<String>,
+    culled: usize,
+}
+
+impl Svg {
+    /// Canvas showing `viewport`, its longest side being `size` pixels.
+    pub fn new(viewport: Viewport, size: f64) -> Self {
+        let longest = viewport.width().max(viewport.height()).max(f64::EPSILON);
+        Svg {
+            viewport,
+            scale: size / longest,
+            elements: vec![],
+            culled: 0,
+        }
+    }
+
+    fn px(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            (x - self.viewport.min_x) * self.scale,
+            (y - self.viewport.min_y) * self.scale,
+        )
+    }
+
+    fn push(&mut self, bounds: Viewport, element: String) {
+        if self.viewport.intersects(&bounds) {
+            self.elements.push(element);
+        } else {
+            self.culled += 1;
+        }
+    }
+
+    /// Shapes dropped for being outside the viewport.
+    pub fn culled(&self) -> usize {
+        self.culled
+    }
+
+    pub fn rect(&mut self, (x, y): (f64, f64), width: f64, height: f64, style: &Style) {
+        let (px, py) = self.px((x, y));
+        let element = format!(
+            "<rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\"{}/>",
+            px,
+            py,
+            width * self.scale,
+            height * self.scale,
+            style.attrs()
+        );
+        self.push(Viewport::new(x, y, x + width, y + height), element);
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)], style: &Style) {
+        self.poly("polygon", points, style);
+    }
+
+    pub fn polyline(&mut self, points: &[(f64, f64)], style: &Style) {
+        self.poly("polyline", points, style);
+    }
+
+    fn poly(&mut self, tag: &str, points: &[(f64, f64)], style: &Style) {
+        let coords: Vec<String> = points
+            .iter()
+            .map(|p| self.px(*p))
+            .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+            .collect();
+        let element = format!("<{} points=\"{}\"{}/>", tag, coords.join(" "), style.attrs());
+        self.push(Viewport::around(points.iter().copied()), element);
+    }
+
+    /// Circle whose radius is in pixels, so it stays visible at any scale.
+    pub fn dot(&mut self, center: (f64, f64), radius: f64, style: &Style) {
+        let (cx, cy) = self.px(center);
+        let element = format!("<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\"{}/>", cx, cy, radius, style.attrs());
+        self.push(Viewport::around([center]), element);
+    }
+
+    /// Text whose size is in pixels.
+    pub fn text(&mut self, at: (f64, f64), size: f64, text: &str, style: &Style) {
+        let (x, y) = self.px(at);
+        let element = format!(
+            "<text x=\"{:.2}\" y=\"{:.2}\" font-size=\"{}\" font-family=\"monospace\"{}>{}</text>",
+            x,
+            y,
+            size,
+            style.attrs(),
+            escape(text)
+        );
+        self.push(Viewport::around([at]), element);
+    }
+
+    pub fn render(&self) -> String {
+        let (width, height) = (self.viewport.width() * self.scale, self.viewport.height() * self.scale);
+
+        let mut res = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0:.0}\" height=\"{1:.0}\" viewBox=\"0 0 {0:.2} {1:.2}\">\n",
+            width, height
+        );
+        res += "<defs><clipPath id=\"viewport\">";
+        let _ = write!(res, "<rect width=\"{:.2}\" height=\"{:.2}\"/>", width, height);
+        res += "</clipPath></defs>\n";
+        res += "<g clip-path=\"url(#viewport)\">\n";
+        self.elements.iter().for_each(|element| {
+            res += element;
+            res += "\n";
+        });
+        res += "</g>\n</svg>\n";
+        res
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.render())
+    }
+}
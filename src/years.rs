@@ -0,0 +1,93 @@
+//! Registry of the years and days the tools know about.
+//!
+//! Inputs live in `inputs/<year>/dayNN.in` (see [`crate::checksum`]), the
+//! solutions are the binaries of `src/bin/<year>` named after [`Year::bin`].
+//! Each year has its entry in a `years/yYYYY.rs` module. Tools take a
+//! `--year` flag, [`DEFAULT_YEAR`] when omitted.
+
+use std::path::{Path, PathBuf};
+
+use crate::{checksum::INPUTS_DIR, cli};
+
+mod y2022;
+
+/// Year of the solutions run without `--year`, as a literal for `concat!` (see
+/// [`crate::input`]).
+#[macro_export]
+macro_rules! default_year {
+    () => {
+        2022
+    };
+}
+
+pub const DEFAULT_YEAR: u32 = crate::default_year!();
+
+/// Picture a day draws with one of its flags.
+pub struct Visualization {
+    pub day: u32,
+    /// Flag taking the output path, e.g. `--image`.
+    pub flag: &'static str,
+    /// File extension, `png` or `svg`.
+    pub ext: &'static str,
+    pub caption: &'static str,
+}
+
+pub struct Year {
+    pub year: u32,
+    /// Prefix of the binary names, `dayNN` following it.
+    pub bin_prefix: &'static str,
+    /// Folder of the solutions, relative to the crate root, each file named
+    /// after its binary and listed as a `[[bin]]` in `Cargo.toml`.
+    pub src_dir: &'static str,
+    pub titles: [&'static str; 25],
+    pub visualizations: &'static [Visualization],
+    /// Days whose input is made of blank-line separated blocks.
+    pub block_days: &'static [u32],
+    /// Days answering `--repl`.
+    pub repl_days: &'static [u32],
+    /// Days exporting a Graphviz graph with `--dot`.
+    pub dot_days: &'static [u32],
+    /// Days answering with another crane model given to `--crane`.
+    pub crane_days: &'static [u32],
+    /// Days reporting on their best entries with `--top K`.
+    pub top_days: &'static [u32],
+}
+
+impl Year {
+    /// Binary of the solution of `day`, e.g. `day05`.
+    pub fn bin(&self, day: u32) -> String {
+        format!("{}day{:02}", self.bin_prefix, day)
+    }
+
+    pub fn title(&self, day: u32) -> &'static str {
+        self.titles[day as usize - 1]
+    }
+
+    /// Folder of the inputs, relative to `root`.
+    pub fn inputs_dir<P: AsRef<Path>>(&self, root: P) -> PathBuf {
+        root.as_ref().join(INPUTS_DIR).join(self.year.to_string())
+    }
+
+    /// Puzzle input of `day`, relative to `root`.
+    pub fn input<P: AsRef<Path>>(&self, root: P, day: u32) -> PathBuf {
+        self.inputs_dir(root).join(format!("day{:02}.in", day))
+    }
+}
+
+pub const YEARS: &[Year] = &[y2022::YEAR];
+
+pub fn get(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+
+/// Year given with `--year`, [`DEFAULT_YEAR`] by default.
+pub fn from_cli() -> Result<&'static Year, String> {
+    let year = match cli::value("--year") {
+        Some(year) => year.parse::<u32>().map_err(|_| format!("Invalid --year: {}", year))?,
+        None => DEFAULT_YEAR,
+    };
+    get(year).ok_or_else(|| {
+        let known: Vec<String> = YEARS.iter().map(|entry| entry.year.to_string()).collect();
+        format!("Unknown year {}, known years: {}", year, known.join(", "))
+    })
+}
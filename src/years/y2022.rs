@@ -0,0 +1,84 @@
+use super::{Visualization, Year};
+
+/// Binaries of 2022 predate the registry and keep their bare `dayNN` names.
+pub(super) const YEAR: Year = Year {
+    year: 2022,
+    bin_prefix: "",
+    src_dir: "src/bin/2022",
+    titles: [
+        "Calorie Counting",
+        "Rock Paper Scissors",
+        "Rucksack Reorganization",
+        "Camp Cleanup",
+        "Supply Stacks",
+        "Tuning Trouble",
+        "No Space Left On Device",
+        "Treetop Tree House",
+        "Rope Bridge",
+        "Cathode-Ray Tube",
+        "Monkey in the Middle",
+        "Hill Climbing Algorithm",
+        "Distress Signal",
+        "Regolith Reservoir",
+        "Beacon Exclusion Zone",
+        "Proboscidea Volcanium",
+        "Pyroclastic Flow",
+        "Boiling Boulders",
+        "Not Enough Minerals",
+        "Grove Positioning System",
+        "Monkey Math",
+        "Monkey Map",
+        "Unstable Diffusion",
+        "Blizzard Basin",
+        "Full of Hot Air",
+    ],
+    visualizations: &[
+        Visualization {
+            day: 8,
+            flag: "--image",
+            ext: "png",
+            caption: "Visible trees (green) and best scenic spot (yellow)",
+        },
+        Visualization {
+            day: 9,
+            flag: "--image",
+            ext: "png",
+            caption: "Cells visited by the tail, 2 and 10 knots",
+        },
+        Visualization {
+            day: 10,
+            flag: "--image",
+            ext: "png",
+            caption: "CRT screen",
+        },
+        Visualization {
+            day: 12,
+            flag: "--image",
+            ext: "png",
+            caption: "Elevation and shortest path",
+        },
+        Visualization {
+            day: 14,
+            flag: "--image",
+            ext: "png",
+            caption: "Sand at rest with the floor",
+        },
+        Visualization {
+            day: 14,
+            flag: "--svg",
+            ext: "svg",
+            caption: "Cave walls",
+        },
+        Visualization {
+            day: 15,
+            flag: "--svg",
+            ext: "svg",
+            caption: "Sensor zones and distress beacon",
+        },
+    ],
+    block_days: &[1, 5, 11, 13],
+    repl_days: &[5, 7, 9, 11],
+    dot_days: &[7, 11, 16],
+    crane_days: &[5],
+    top_days: &[1],
+};
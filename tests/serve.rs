@@ -0,0 +1,105 @@
+//! `aoc serve` against the real day binaries, through a local client.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+};
+
+/// `aoc serve` on a free port, killed when dropped.
+struct Serve {
+    child: Child,
+    addr: String,
+}
+
+impl Serve {
+    fn start(timeout: &str) -> Serve {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0", "--timeout", timeout])
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+        let mut line = String::new();
+        stderr.read_line(&mut line).unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("Serve:: listening on http://")
+            .unwrap_or_else(|| panic!("Unexpected first line: {}", line))
+            .to_owned();
+        // Keeps the request log from filling the pipe.
+        std::thread::spawn(move || std::io::copy(&mut stderr, &mut std::io::sink()));
+
+        Serve { child, addr }
+    }
+
+    /// Status line and body of the answer.
+    fn post(&self, path: &str, body: &str) -> (String, String) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        let raw = format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        );
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut answer = String::new();
+        stream.read_to_string(&mut answer).unwrap();
+        let (head, body) = answer.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_owned(), body.to_owned())
+    }
+}
+
+impl Drop for Serve {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const DAY01: &str = include_str!("../inputs/2022/day01.test");
+
+#[test]
+fn solves_with_the_day_binaries() {
+    let serve = Serve::start("30");
+
+    let (status, body) = serve.post("/day/1/part/1", DAY01);
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(
+        body.starts_with("{\"year\":2022,\"day\":1,\"part\":1,\"answer\":\"24000\",\"time_ns\":"),
+        "{}",
+        body
+    );
+    assert!(body.contains(",\"elapsed_ns\":"), "{}", body);
+    let (_, body) = serve.post("/year/2022/day/1/part/2", DAY01);
+    assert!(body.contains("\"answer\":\"45000\""), "{}", body);
+
+    let (status, body) = serve.post("/day/1/part/1", "x\n");
+    assert_eq!(status, "HTTP/1.1 422 Unprocessable Content");
+    assert!(body.contains("day01 failed on this input at src/bin/2022/day01.rs:"), "{}", body);
+
+    let (status, body) = serve.post("/day/11/part/2", include_str!("../inputs/2022/day11.test"));
+    assert_eq!(status, "HTTP/1.1 501 Not Implemented");
+    assert_eq!(body, "{\"error\":\"Day 11 part 2 of 2022 is not solved\"}");
+
+    let (status, body) = serve.post("/day/25/part/1", "");
+    assert_eq!(status, "HTTP/1.1 404 Not Found");
+    assert_eq!(body, "{\"error\":\"Day 25 of 2022 is not solved\"}");
+    assert_eq!(serve.post("/year/1999/day/1/part/1", DAY01).0, "HTTP/1.1 404 Not Found");
+}
+
+#[test]
+fn times_out_slow_days() {
+    let serve = Serve::start("3");
+    let day15 = include_str!("../inputs/2022/day15.in");
+
+    // Part 2 of day 15 scans the whole search area, part 1 a single row.
+    let (status, body) = serve.post("/day/15/part/2", day15);
+    assert_eq!(status, "HTTP/1.1 504 Gateway Timeout");
+    assert!(body.starts_with("{\"error\":\"day15 did not answer within"), "{}", body);
+
+    let (status, body) = serve.post("/day/15/part/1", day15);
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(body.contains("\"answer\":\"4737443\""), "{}", body);
+}